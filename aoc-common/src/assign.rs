@@ -0,0 +1,60 @@
+//! Giving each key a different one of its candidate values, as when
+//! working out which ticket field is which rule, or which ingredient holds
+//! which allergen.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A value for every key in `candidates`, picked from that key's candidates
+/// with no value used twice, or `None` if there is no such assignment.
+///
+/// Searches depth first, always assigning the key with the fewest values
+/// left next, and backtracks when some key has none left.
+pub fn assign<K, V>(candidates: &HashMap<K, HashSet<V>>) -> Option<HashMap<K, V>>
+    where K: Copy + Eq + Hash,
+          V: Copy + Eq + Hash
+{
+    let mut keys: HashSet<K> = candidates.keys().copied().collect();
+    let mut assignment = HashMap::new();
+    if search(candidates, &mut keys, &mut HashSet::new(), &mut assignment) {
+        Some(assignment)
+    }
+    else {
+        None
+    }
+}
+
+fn search<K, V>(candidates: &HashMap<K, HashSet<V>>, keys: &mut HashSet<K>, taken: &mut HashSet<V>, assignment: &mut HashMap<K, V>) -> bool
+    where K: Copy + Eq + Hash,
+          V: Copy + Eq + Hash
+{
+    if keys.is_empty() {
+        return true;
+    }
+
+    // find most constrained key
+    let (key, values) = keys.iter()
+        .map(|&key| {
+            (key, candidates[&key].iter().filter(|v| !taken.contains(*v)).copied().collect::<Vec<V>>())
+        })
+        .min_by_key(|(_, v)| v.len()).unwrap();
+
+    // no values left for this key, backtrack
+    if values.is_empty() {
+        return false;
+    }
+
+    keys.remove(&key);
+    for value in values {
+        taken.insert(value);
+        if search(candidates, keys, taken, assignment) {
+            assignment.insert(key, value);
+            return true;
+        }
+
+        taken.remove(&value);
+    }
+
+    keys.insert(key);
+    false // backtrack
+}
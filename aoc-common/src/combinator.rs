@@ -0,0 +1,230 @@
+//! Parser combinators for puzzle input.
+//!
+//! A parser is a function from the remaining input to the value it parsed
+//! and the input left after it. When it fails, its [`Error`] records what it
+//! expected and where, as a slice of the input, so that it becomes a
+//! [`Diagnostic`] like `expected '-', found ':'` pointing at the right
+//! character. Parsers that take arguments, like [`tag`], return closures, and
+//! sequences are usually clearest written out with `?`:
+//!
+//! ```
+//! use aoc_common::combinator::{complete, number, tag, PResult};
+//!
+//! fn range(s: &str) -> PResult<'_, (u32, u32)> {
+//!     let (low, s) = number(s)?;
+//!     let (_, s) = tag("-")(s)?;
+//!     let (high, s) = number(s)?;
+//!     Ok(((low, high), s))
+//! }
+//!
+//! assert_eq!(complete(range, "1-3").unwrap(), (1, 3));
+//! assert_eq!(complete(range, "1:3").unwrap_err().to_string(), "expected '-', found ':'");
+//! ```
+
+use crate::diagnostic::Diagnostic;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The value a parser parsed and the input left after it.
+pub type PResult<'a, T> = Result<(T, &'a str), Error<'a>>;
+
+/// What a parser expected to find, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    at: &'a str,
+    expected: Vec<String>,
+}
+
+impl<'a> Error<'a> {
+    /// An error at the start of `at`, the input that was left when the
+    /// parser failed. `expected` describes what should have been there,
+    /// e.g. `'-'` or `a number`.
+    pub fn new(at: &'a str, expected: impl Into<String>) -> Self {
+        Self { at, expected: vec![expected.into()] }
+    }
+
+    /// The error from whichever of two alternatives got further, or both
+    /// of their expectations if they failed at the same place.
+    fn merge(mut self, other: Self) -> Self {
+        match self.at.len().cmp(&other.at.len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => {
+                for e in other.expected {
+                    if !self.expected.contains(&e) {
+                        self.expected.push(e);
+                    }
+                }
+                self
+            },
+        }
+    }
+}
+
+impl From<Error<'_>> for Diagnostic {
+    fn from(e: Error<'_>) -> Self {
+        let (found, description) = found(e.at);
+        let expected = match e.expected.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+            None => "nothing".to_string(),
+        };
+        Diagnostic::new(found, format!("expected {}, found {}", expected, description))
+    }
+}
+
+impl From<Error<'_>> for anyhow::Error {
+    fn from(e: Error<'_>) -> Self {
+        Diagnostic::from(e).into()
+    }
+}
+
+/// The token at the start of `s` that a parser tripped over: a run of
+/// letters and digits, or else a single character.
+fn found(s: &str) -> (&str, String) {
+    let len = match s.chars().next() {
+        None | Some('\n') | Some('\r') => return (&s[..0], "end of line".to_string()),
+        Some(c) if c.is_alphanumeric() => s.find(|c: char| !c.is_alphanumeric()).unwrap_or(s.len()),
+        Some(c) => c.len_utf8(),
+    };
+    (&s[..len], format!("'{}'", &s[..len]))
+}
+
+/// Runs `parser` on the whole of `s`, which must leave nothing over.
+pub fn complete<'a, T>(parser: impl Fn(&'a str) -> PResult<'a, T>, s: &'a str) -> anyhow::Result<T> {
+    let (value, rest) = parser(s)?;
+    if !rest.is_empty() {
+        return Err(Error::new(rest, "end of line").into());
+    }
+    Ok(value)
+}
+
+/// Matches `literal` exactly.
+pub fn tag<'a>(literal: &'static str) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |s: &'a str| {
+        if s.starts_with(literal) {
+            Ok(s.split_at(literal.len()))
+        }
+        else {
+            Err(Error::new(s, format!("'{}'", literal)))
+        }
+    }
+}
+
+/// Matches the first of `choices` whose literal starts the input, giving
+/// its value.
+pub fn one_of<'a, T: Copy>(choices: &'static [(&'static str, T)]) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |s: &'a str| {
+        choices.iter()
+            .find(|(literal, _)| s.starts_with(literal))
+            .map(|&(literal, value)| (value, &s[literal.len()..]))
+            .ok_or_else(|| Error {
+                at: s,
+                expected: choices.iter().map(|(literal, _)| format!("'{}'", literal)).collect(),
+            })
+    }
+}
+
+/// Matches one character satisfying `pred`. `expected` describes such a
+/// character for errors.
+pub fn satisfy<'a>(expected: &'static str, pred: impl Fn(char) -> bool) -> impl Fn(&'a str) -> PResult<'a, char> {
+    move |s: &'a str| match s.chars().next() {
+        Some(c) if pred(c) => Ok((c, &s[c.len_utf8()..])),
+        _ => Err(Error::new(s, expected)),
+    }
+}
+
+/// Matches one or more characters satisfying `pred`. `expected` describes
+/// them for errors.
+pub fn take_while1<'a>(expected: &'static str, pred: impl Fn(char) -> bool) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |s: &'a str| {
+        let len = s.find(|c| !pred(c)).unwrap_or(s.len());
+        if len == 0 {
+            return Err(Error::new(s, expected));
+        }
+        Ok(s.split_at(len))
+    }
+}
+
+/// Matches everything before the first `delimiter`, which is left in the
+/// input. Fails at the end of the input if there is no `delimiter`.
+pub fn take_until<'a>(delimiter: &'static str) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |s: &'a str| match s.find(delimiter) {
+        Some(i) => Ok(s.split_at(i)),
+        None => Err(Error::new(&s[s.len()..], format!("'{}'", delimiter))),
+    }
+}
+
+/// Matches a decimal number without a sign.
+pub fn number<T>(s: &str) -> PResult<'_, T>
+    where T: FromStr,
+          T::Err: Display
+{
+    let (digits, rest) = take_while1("a number", |c| c.is_ascii_digit())(s)?;
+    match digits.parse() {
+        Ok(n) => Ok((n, rest)),
+        Err(e) => Err(Error::new(s, format!("a number ({})", e))),
+    }
+}
+
+/// Transforms what `parser` matched with `f`.
+pub fn map<'a, T, U>(parser: impl Fn(&'a str) -> PResult<'a, T>, f: impl Fn(T) -> U) -> impl Fn(&'a str) -> PResult<'a, U> {
+    move |s: &'a str| parser(s).map(|(value, rest)| (f(value), rest))
+}
+
+/// Matches `parser` if it can, and otherwise nothing.
+pub fn opt<'a, T>(parser: impl Fn(&'a str) -> PResult<'a, T>) -> impl Fn(&'a str) -> PResult<'a, Option<T>> {
+    move |s: &'a str| match parser(s) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, s)),
+    }
+}
+
+/// Matches `a`, or else `b`. If both fail, the error is from the one that
+/// got further, or lists what both expected.
+pub fn or<'a, T>(a: impl Fn(&'a str) -> PResult<'a, T>, b: impl Fn(&'a str) -> PResult<'a, T>) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |s: &'a str| a(s).or_else(|ea| b(s).map_err(|eb| ea.merge(eb)))
+}
+
+/// Matches `parser` one or more times, for as long as it matches.
+pub fn many1<'a, T>(parser: impl Fn(&'a str) -> PResult<'a, T>) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |s: &'a str| {
+        let (first, mut s) = parser(s)?;
+        let mut items = vec![first];
+        while let Ok((next, rest)) = parser(s) {
+            if rest.len() == s.len() {
+                break;
+            }
+            items.push(next);
+            s = rest;
+        }
+        Ok((items, s))
+    }
+}
+
+/// Matches one or more of `item`, separated by `separator`. A separator
+/// must be followed by another item.
+pub fn separated_list1<'a, T, S>(item: impl Fn(&'a str) -> PResult<'a, T>, separator: impl Fn(&'a str) -> PResult<'a, S>)
+    -> impl Fn(&'a str) -> PResult<'a, Vec<T>>
+{
+    move |s: &'a str| {
+        let (first, mut s) = item(s)?;
+        let mut items = vec![first];
+        while let Ok((_, rest)) = separator(s) {
+            let (next, rest) = item(rest)?;
+            items.push(next);
+            s = rest;
+        }
+        Ok((items, s))
+    }
+}
+
+/// Matches `parser`, also giving the slice of input it matched, e.g. to
+/// point a later [`Diagnostic`] at it.
+pub fn spanned<'a, T>(parser: impl Fn(&'a str) -> PResult<'a, T>) -> impl Fn(&'a str) -> PResult<'a, (T, &'a str)> {
+    move |s: &'a str| {
+        let (value, rest) = parser(s)?;
+        Ok(((value, &s[..s.len() - rest.len()]), rest))
+    }
+}
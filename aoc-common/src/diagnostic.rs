@@ -0,0 +1,149 @@
+//! Parse errors that point at the offending part of the puzzle input.
+//!
+//! Parsers work on slices of the input, so a [`Diagnostic`] only records
+//! which slice it is about. [`crate::solve`] later locates that slice in the
+//! whole input to find its line and column, so nested parsers don't need to
+//! thread line numbers through:
+//!
+//! ```text
+//! invalid step 'X', expected one of 'F', 'B', 'L' or 'R'
+//!  --> day05/input.txt:3:7
+//!   |
+//! 3 | FBFBBFXRLR
+//!   |       ^
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    message: String,
+    span: Range<usize>,
+    file: Option<String>,
+    location: Option<Location>,
+}
+
+/// Where a [`Diagnostic`]'s span is in the input. Lines and columns are
+/// 1-based and columns count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// The number of characters to underline, at least 1.
+    pub len: usize,
+    /// The whole line containing the span.
+    pub excerpt: String,
+}
+
+impl Diagnostic {
+    /// An error about `span`, which should be a slice of the puzzle input
+    /// so that it can be located later. An empty slice points between two
+    /// characters, e.g. at the end of the input for an unexpected EOF.
+    pub fn new(span: &str, message: impl Into<String>) -> Self {
+        let start = span.as_ptr() as usize;
+        Self {
+            message: message.into(),
+            span: start..start + span.len(),
+            file: None,
+            location: None,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    pub fn set_file(&mut self, file: impl Into<String>) {
+        self.file = Some(file.into());
+    }
+
+    /// Finds the span in `input`. Does nothing if the span is not a slice
+    /// of `input`, e.g. because it was built from a `String` the parser
+    /// made itself.
+    pub fn locate(&mut self, input: &str) {
+        let base = input.as_ptr() as usize;
+        if self.span.start < base || self.span.end > base + input.len() {
+            return;
+        }
+
+        let start = self.span.start - base;
+        let end = self.span.end - base;
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        let excerpt = input[line_start..line_end].trim_end_matches('\r');
+
+        let line = input[..start].matches('\n').count() + 1;
+        let column = input[line_start..start].chars().count() + 1;
+        let len = input[start..end.min(line_end)].chars().count().max(1);
+
+        self.location = Some(Location { line, column, len, excerpt: excerpt.to_string() });
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        let location = match &self.location {
+            Some(location) => location,
+            None => return Ok(()),
+        };
+
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let gutter = location.line.to_string().len();
+        writeln!(f)?;
+        writeln!(f, "{:gutter$}--> {}:{}:{}", "", file, location.line, location.column, gutter = gutter)?;
+        writeln!(f, "{:gutter$} |", "", gutter = gutter)?;
+        writeln!(f, "{} | {}", location.line, location.excerpt)?;
+        write!(f, "{:gutter$} | {:indent$}{}", "", "", "^".repeat(location.len),
+            gutter = gutter, indent = location.column - 1)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// Turns other errors into [`Diagnostic`]s.
+pub trait ResultExt<T> {
+    /// Attaches `span` to an error, using the error's own message. Errors
+    /// that are already diagnostics are passed through unchanged, since
+    /// they point somewhere more specific.
+    fn at(self, span: &str) -> anyhow::Result<T>;
+}
+
+impl<T, E: Into<anyhow::Error>> ResultExt<T> for Result<T, E> {
+    fn at(self, span: &str) -> anyhow::Result<T> {
+        self.map_err(|e| {
+            let e: anyhow::Error = e.into();
+            if e.is::<Diagnostic>() {
+                e
+            }
+            else {
+                Diagnostic::new(span, format!("{:#}", e)).into()
+            }
+        })
+    }
+}
+
+/// Locates any diagnostic in `e` within `input`.
+pub fn locate(mut e: anyhow::Error, input: &str) -> anyhow::Error {
+    if let Some(d) = e.downcast_mut::<Diagnostic>() {
+        d.locate(input);
+    }
+    e
+}
+
+/// Names the file any diagnostic in `e` was found in.
+pub fn set_file(e: &mut anyhow::Error, file: &str) {
+    if let Some(d) = e.downcast_mut::<Diagnostic>() {
+        d.set_file(file);
+    }
+}
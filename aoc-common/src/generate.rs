@@ -0,0 +1,43 @@
+//! Random puzzle inputs, for property tests and for `aoc gen`.
+//!
+//! Each day has a `generate::input(rng, size)` that writes a valid input in
+//! that day's format. `size` roughly controls the number of records (lines,
+//! passports, rules, ...); the same seed and size always give the same input.
+
+pub use rand::rngs::StdRng;
+pub use rand::seq::{IndexedRandom, SliceRandom};
+pub use rand::Rng;
+
+use rand::SeedableRng;
+
+/// A generator of puzzle input text.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Runs `generator` with an rng seeded from `seed`.
+pub fn generate(generator: Generator, seed: u64, size: usize) -> String {
+    generator(&mut rng(seed), size)
+}
+
+/// `len` characters picked uniformly from `alphabet`.
+pub fn word(rng: &mut StdRng, len: usize, alphabet: &str) -> String {
+    let chars: Vec<char> = alphabet.chars().collect();
+    (0..len).map(|_| *chars.choose(rng).unwrap()).collect()
+}
+
+/// A `rows` by `cols` grid of characters from `cells`, one row per line.
+pub fn grid(rng: &mut StdRng, rows: usize, cols: usize, cells: &str) -> String {
+    (0..rows)
+        .map(|_| word(rng, cols, cells) + "\n")
+        .collect()
+}
+
+/// Lines of `items`, each followed by a newline.
+pub fn lines<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter()
+        .map(|item| item.to_string() + "\n")
+        .collect()
+}
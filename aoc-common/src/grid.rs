@@ -0,0 +1,201 @@
+use crate::diagnostic::{Diagnostic, ResultExt};
+use anyhow::Result;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A `(row, col)` position in a [`Grid`]. Signed so that neighbours of edge
+/// cells can be computed before being bounds checked.
+pub type Coord = (i64, i64);
+
+/// A dense, row-major 2D grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: i64,
+    cols: i64,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: i64, cols: i64, cells: Vec<T>) -> Self {
+        assert_eq!((rows * cols) as usize, cells.len(), "cell count does not match grid size");
+        Self { rows, cols, cells }
+    }
+
+    pub fn rows(&self) -> i64 {
+        self.rows
+    }
+
+    pub fn cols(&self) -> i64 {
+        self.cols
+    }
+
+    pub fn contains(&self, &(row, col): &Coord) -> bool {
+        row >= 0 && row < self.rows && col >= 0 && col < self.cols
+    }
+
+    pub fn get(&self, coord: &Coord) -> Option<&T> {
+        if self.contains(coord) {
+            Some(&self.cells[self.idx(coord)])
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, coord: &Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            let idx = self.idx(coord);
+            Some(&mut self.cells[idx])
+        }
+        else {
+            None
+        }
+    }
+
+    /// Iterates over every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let rows = self.rows;
+        let cols = self.cols;
+        (0..rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.rows, self.cols, self.cells.iter().map(f).collect())
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    pub fn rotate(&self) -> Self
+        where T: Clone
+    {
+        let cells = (0..self.cols)
+            .flat_map(|c| (0..self.rows).rev().map(move |r| (r, c)))
+            .map(|coord| self[coord].clone())
+            .collect();
+        Grid::new(self.cols, self.rows, cells)
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip(&self) -> Self
+        where T: Clone
+    {
+        let cells = self.coords()
+            .map(|(r, c)| self[(r, self.cols - 1 - c)].clone())
+            .collect();
+        Grid::new(self.rows, self.cols, cells)
+    }
+
+    /// The grid in each of its 4 rotations, each followed by its mirror
+    /// image.
+    pub fn orientations(&self) -> Vec<Self>
+        where T: Clone
+    {
+        let mut grid = self.clone();
+        let mut orientations = Vec::with_capacity(8);
+        for _ in 0..4 {
+            let next = grid.rotate();
+            let flipped = grid.flip();
+            orientations.push(grid);
+            orientations.push(flipped);
+            grid = next;
+        }
+        orientations
+    }
+
+    /// The in-bounds coordinates of the (up to) 8 cells around `coord`.
+    pub fn adjacent(&self, &(row, col): &Coord) -> impl Iterator<Item = Coord> {
+        let rows = self.rows;
+        let cols = self.cols;
+        (row-1..=row+1).flat_map(move |r| (col-1..=col+1).map(move |c| (r, c)))
+            .filter(move |&(r, c)| r >= 0 && r < rows && c >= 0 && c < cols && (r, c) != (row, col))
+    }
+
+    fn idx(&self, &(row, col): &Coord) -> usize {
+        (row * self.cols + col) as usize
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        assert!(self.contains(&coord), "{:?} is outside the grid", coord);
+        &self.cells[self.idx(&coord)]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        assert!(self.contains(&coord), "{:?} is outside the grid", coord);
+        let idx = self.idx(&coord);
+        &mut self.cells[idx]
+    }
+}
+
+impl<T> FromStr for Grid<T>
+    where T: FromChar,
+          T::Err: Into<anyhow::Error>
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let rows = s.lines().count() as i64;
+        let first = s.lines().next().ok_or_else(|| Diagnostic::new(s, "grid does not have any rows"))?;
+        let cols = first.chars().count() as i64;
+        if cols == 0 {
+            return Err(Diagnostic::new(first, "grid row is empty").into());
+        }
+
+        let mut cells = Vec::with_capacity((rows * cols) as usize);
+        for line in s.lines() {
+            let len = line.chars().count() as i64;
+            if len != cols {
+                return Err(Diagnostic::new(line, format!("row has {} columns, expected {}", len, cols)).into());
+            }
+
+            for (i, c) in line.char_indices() {
+                cells.push(c.parse().at(&line[i..i + c.len_utf8()])?);
+            }
+        }
+
+        Ok(Self { rows, cols, cells })
+    }
+}
+
+/// Writes one row per line, the inverse of parsing a grid.
+impl<T: ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.cols.max(1) as usize) {
+            let line: String = row.iter().map(ToChar::to_char).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Like `FromStr`, for types that are parsed from a single character.
+pub trait FromChar: Sized {
+    type Err;
+    fn from_char(c: &char) -> std::result::Result<Self, Self::Err>;
+}
+
+/// The inverse of [`FromChar`].
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+/// Adds `parse` to `char`, mirroring `str::parse`.
+pub trait CharParseExt<T: FromChar> {
+    fn parse(&self) -> std::result::Result<T, T::Err>;
+}
+
+impl<T: FromChar> CharParseExt<T> for char {
+    fn parse(&self) -> std::result::Result<T, T::Err> {
+        T::from_char(self)
+    }
+}
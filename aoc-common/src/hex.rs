@@ -0,0 +1,85 @@
+//! Hexagonal grids with rows running east to west, in axial coordinates:
+//! `q` increases to the east and `r` to the south-east.
+
+use crate::life;
+use std::ops::Add;
+
+/// A hex in a [hexagonal grid](self).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// The hex next to this one in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The number of steps between two hexes.
+    pub fn distance(self, other: Hex) -> i64 {
+        let (dq, dr) = (self.q - other.q, self.r - other.r);
+        (dq.abs() + dr.abs() + (dq + dr).abs()) / 2
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl life::Point for Hex {
+    fn neighbors(&self) -> Vec<Self> {
+        Direction::ALL.iter().map(|&d| self.step(d)).collect()
+    }
+}
+
+/// The six directions to a neighbouring hex.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    E,
+    SE,
+    SW,
+    W,
+    NW,
+    NE,
+}
+
+impl Direction {
+    /// Clockwise from east.
+    pub const ALL: [Direction; 6] = [Direction::E, Direction::SE, Direction::SW, Direction::W, Direction::NW, Direction::NE];
+
+    /// The hex one step in this direction from the origin.
+    pub fn offset(self) -> Hex {
+        use Direction::*;
+        match self {
+            E => Hex::new(1, 0),
+            SE => Hex::new(0, 1),
+            SW => Hex::new(-1, 1),
+            W => Hex::new(-1, 0),
+            NW => Hex::new(0, -1),
+            NE => Hex::new(1, -1),
+        }
+    }
+
+    /// The direction's abbreviation, like `se`.
+    pub fn name(self) -> &'static str {
+        use Direction::*;
+        match self {
+            E => "e",
+            SE => "se",
+            SW => "sw",
+            W => "w",
+            NW => "nw",
+            NE => "ne",
+        }
+    }
+}
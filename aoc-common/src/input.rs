@@ -0,0 +1,132 @@
+//! Locating and reading puzzle input.
+//!
+//! Input is read from stdin (`-`), from an explicit path, or from
+//! `<input dir>/dayNN/input.txt`. The input dir is, in order of preference:
+//!
+//! 1. the `AOC_INPUT_DIR` environment variable,
+//! 2. `input_dir` in the nearest `aoc.toml` in the current directory or one
+//!    of its ancestors (relative paths are relative to the `aoc.toml`),
+//! 3. the root of this workspace.
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    /// A file given explicitly by the user.
+    Path(PathBuf),
+    /// A day's file in the input dir, with a description of where the input
+    /// dir setting came from.
+    InputDir { path: PathBuf, origin: String },
+}
+
+impl InputSource {
+    /// The input for `day` in the configured input dir.
+    pub fn for_day(day: u8) -> Result<Self> {
+        let (dir, origin) = input_dir()?;
+        let path = dir.join(format!("day{:02}", day)).join("input.txt");
+        Ok(InputSource::InputDir { path, origin })
+    }
+
+    /// An explicit `--input`, falling back to the input dir when absent.
+    pub fn or_for_day(input: Option<InputSource>, day: u8) -> Result<Self> {
+        match input {
+            Some(input) => Ok(input),
+            None => Self::for_day(day),
+        }
+    }
+
+    /// Whether this is a day's file in the input dir that doesn't exist,
+    /// e.g. because that day's puzzle input hasn't been downloaded.
+    pub fn is_missing_default(&self) -> bool {
+        match self {
+            InputSource::InputDir { path, .. } => !path.is_file(),
+            _ => false,
+        }
+    }
+
+    /// How to refer to the input in error messages.
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::Stdin => "<stdin>".to_string(),
+            _ => self.to_string(),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut s = String::new();
+                std::io::stdin().read_to_string(&mut s).context("could not read input from stdin")?;
+                Ok(s)
+            },
+            InputSource::Path(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("could not read input '{}'", path.display())),
+            InputSource::InputDir { path, origin } => std::fs::read_to_string(path)
+                .with_context(|| format!("could not read input '{}' (input dir from {})", path.display(), origin)),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "" => Err(anyhow!("input path is empty")),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(path.into())),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "-"),
+            InputSource::Path(path) | InputSource::InputDir { path, .. } => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Config {
+    input_dir: Option<PathBuf>,
+}
+
+/// The input dir, along with a description of where it was configured.
+pub fn input_dir() -> Result<(PathBuf, String)> {
+    if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+        return Ok((dir.into(), format!("${}", INPUT_DIR_VAR)));
+    }
+
+    let cwd = std::env::current_dir().context("could not get the current directory")?;
+    for dir in cwd.ancestors() {
+        let config_path = dir.join(CONFIG_FILE);
+        if !config_path.is_file() {
+            continue;
+        }
+
+        let config = read_config(&config_path)?;
+        if let Some(input_dir) = config.input_dir {
+            return Ok((dir.join(input_dir), config_path.display().to_string()));
+        }
+    }
+
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    Ok((workspace.to_path_buf(), "the default".to_string()))
+}
+
+fn read_config(path: &Path) -> Result<Config> {
+    let s = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read config '{}'", path.display()))?;
+    toml::from_str(&s).with_context(|| format!("invalid config '{}'", path.display()))
+}
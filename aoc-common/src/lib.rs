@@ -0,0 +1,116 @@
+use anyhow::{anyhow, Result};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use input::InputSource;
+
+pub mod assign;
+pub mod combinator;
+pub mod diagnostic;
+pub mod generate;
+pub mod grid;
+pub mod hex;
+pub mod input;
+pub mod life;
+pub mod modular;
+pub mod multiset;
+pub mod parse;
+pub mod regression;
+pub mod render;
+pub mod vec2;
+
+/// A solution to one day's puzzle.
+///
+/// `parse` turns the raw puzzle input into `Input`, which is then shared by
+/// both parts. `Input` may borrow from the raw input string.
+pub trait Solver {
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2>;
+}
+
+/// The rendered answer to one part, or the error that part failed with.
+pub type Answer = Result<String>;
+
+/// The answers to one run of a solver, with how long each stage took.
+pub struct Solution {
+    pub parse_time: Duration,
+    pub parts: Vec<PartSolution>,
+}
+
+pub struct PartSolution {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// Parses `input` once and solves each of the requested `parts`.
+///
+/// Only a parse failure fails the whole call; a failing part is reported
+/// alongside the answers to the other parts. Any [`diagnostic::Diagnostic`]
+/// in an error is located in `input`.
+pub fn solve<S: Solver>(input: &str, parts: &[u8]) -> Result<Solution> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| diagnostic::locate(e, input))?;
+    let parse_time = start.elapsed();
+
+    let parts = parts.iter()
+        .map(|&part| {
+            let mut solution = solve_part::<S>(&parsed, part);
+            solution.answer = solution.answer.map_err(|e| diagnostic::locate(e, input));
+            solution
+        })
+        .collect();
+    Ok(Solution { parse_time, parts })
+}
+
+fn solve_part<S: Solver>(input: &S::Input<'_>, part: u8) -> PartSolution {
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(input).map(|a| a.to_string()),
+        2 => S::part2(input).map(|a| a.to_string()),
+        _ => Err(anyhow!("invalid part {}", part)),
+    };
+    let time = start.elapsed();
+
+    PartSolution { part, answer, time }
+}
+
+/// Solves the requested `parts` of `input` and prints their answers.
+///
+/// Every part that succeeds is printed; the first failure is returned after
+/// all parts have run.
+pub fn run<S: Solver>(input: &str, parts: &[u8]) -> Result<()> {
+    let mut result = Ok(());
+    for PartSolution { part, answer, .. } in solve::<S>(input, parts)?.parts {
+        match answer {
+            Ok(answer) => println!("part{} = {}", part, answer),
+            Err(e) => if result.is_ok() {
+                result = Err(e.context(format!("part {} failed", part)));
+            },
+        }
+    }
+    result
+}
+
+/// Entry point for the per-day binaries, which take an optional input path
+/// (or `-` for stdin) and otherwise read the day's file in the input dir.
+pub fn main<S: Solver>(day: u8) -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let input = match &args[..] {
+        [] => None,
+        [input] => Some(input.parse()?),
+        _ => return Err(anyhow!("usage: day{:02} [INPUT]", day)),
+    };
+
+    let input = InputSource::or_for_day(input, day)?;
+    let input_str = input.read()?;
+    run::<S>(&input_str, &[1, 2]).map_err(|mut e| {
+        diagnostic::set_file(&mut e, &input.name());
+        e
+    })
+}
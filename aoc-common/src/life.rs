@@ -0,0 +1,28 @@
+//! Cellular automata over an infinite space, storing only the live cells.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A cell in a [`step`]ped space.
+pub trait Point: Copy + Eq + Hash + Sized {
+    /// Every cell that counts as a neighbour.
+    fn neighbors(&self) -> Vec<Self>;
+}
+
+/// The live cells after one generation. `rule` is given whether a cell is
+/// live and how many of its neighbours are, and says whether it lives on.
+/// Only cells that are live or next to one are considered, so a cell with
+/// no live neighbours can't come to life.
+pub fn step<T: Point>(live: &HashSet<T>, rule: impl Fn(bool, usize) -> bool) -> HashSet<T> {
+    let mut live_neighbors: HashMap<T, (bool, usize)> = live.iter().map(|&p| (p, (true, 0))).collect();
+    for point in live.iter() {
+        for neighbor in point.neighbors() {
+            live_neighbors.entry(neighbor).or_insert((false, 0)).1 += 1;
+        }
+    }
+
+    live_neighbors.into_iter()
+        .filter(|&(_, (live, count))| rule(live, count))
+        .map(|(p, _)| p)
+        .collect()
+}
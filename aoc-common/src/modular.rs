@@ -0,0 +1,91 @@
+//! Arithmetic modulo an integer.
+
+use std::collections::HashMap;
+
+/// Bézout coefficients `s` and `t` and the gcd `r` of `a` and `b`, with
+/// `a * s + b * t == r`, as `(s, t, r)`.
+// https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm#Pseudocode
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        let r_vals = (r, old_r - quotient * r);
+        let s_vals = (s, old_s - quotient * s);
+        let t_vals = (t, old_t - quotient * t);
+
+        old_r = r_vals.0;
+        r = r_vals.1;
+        old_s = s_vals.0;
+        s = s_vals.1;
+        old_t = t_vals.0;
+        t = t_vals.1;
+    }
+
+    (old_s, old_t, old_r)
+}
+
+/// `a * b % modulus`, without overflowing.
+pub fn mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base` to the power `exp`, modulo `modulus`, by repeated squaring.
+pub fn pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut base = base % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul(result, base, modulus);
+        }
+        base = mul(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// The `x` with `a * x % modulus == 1`, if `a` and `modulus` are coprime.
+pub fn inverse(a: u64, modulus: u64) -> Option<u64> {
+    let (s, _, r) = extended_gcd((a % modulus) as i64, modulus as i64);
+    if r != 1 {
+        return None;
+    }
+    Some(s.rem_euclid(modulus as i64) as u64)
+}
+
+/// The smallest `x` with `pow(base, x, modulus) == target`, found by
+/// baby-step giant-step in about `sqrt(modulus)` time and space.
+///
+/// `base` must be coprime to `modulus`. `None` if it isn't, or if no power
+/// of `base` is `target`.
+// https://en.wikipedia.org/wiki/Baby-step_giant-step
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    if target >= modulus {
+        return None;
+    }
+
+    let m = (modulus as f64).sqrt().ceil() as u64;
+
+    // base^j for every j < m, keeping the smallest j for each power.
+    let mut baby_steps = HashMap::new();
+    let mut power = 1 % modulus;
+    for j in 0..m {
+        baby_steps.entry(power).or_insert(j);
+        power = mul(power, base, modulus);
+    }
+
+    // target * base^(-m * i) is base^j exactly when base^(m * i + j) is
+    // target. Every power of base repeats within `modulus` steps, so `i`
+    // need not go past `m`.
+    let giant_step = inverse(pow(base, m, modulus), modulus)?;
+    let mut gamma = target;
+    for i in 0..=m {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * m + j);
+        }
+        gamma = mul(gamma, giant_step, modulus);
+    }
+    None
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/// A set that counts how many times each element has been added, so an
+/// element is only gone once it has been removed as often as it was added.
+#[derive(Debug, Clone)]
+pub struct RefCountSet<T>
+    where T: Eq + Hash
+{
+    count: HashMap<T, usize>,
+}
+
+impl<T> RefCountSet<T>
+    where T: Eq + Hash
+{
+    pub fn new() -> Self {
+        RefCountSet{ count: HashMap::new() }
+    }
+
+    pub fn add(&mut self, elem: T) {
+        let c = self.count.entry(elem).or_insert(0);
+        *c += 1;
+    }
+
+    /// Removes one copy of `elem`, returning false if there was none.
+    pub fn remove(&mut self, elem: &T) -> bool {
+        let c = match self.count.get_mut(elem) {
+            Some(c) => c,
+            None => return false,
+        };
+
+        *c -= 1;
+        if *c == 0 {
+            self.count.remove(elem);
+        }
+        true
+    }
+
+    pub fn contains(&self, elem: &T) -> bool {
+        self.count.contains_key(elem)
+    }
+
+    /// The number of copies of `elem` in the set.
+    pub fn count(&self, elem: &T) -> usize {
+        self.count.get(elem).copied().unwrap_or(0)
+    }
+
+    /// Iterates over the distinct elements, each paired with its count.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.count.iter().map(|(elem, &count)| (elem, count))
+    }
+}
+
+impl<T> Default for RefCountSet<T>
+    where T: Eq + Hash
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for RefCountSet<T>
+    where T: Eq + Hash
+{
+    fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
+        let mut c = Self::new();
+        for i in iter {
+            c.add(i);
+        }
+        c
+    }
+}
@@ -0,0 +1,23 @@
+use crate::diagnostic::ResultExt;
+use anyhow::Result;
+use std::str::FromStr;
+
+/// Parses every line of `s` as a `T`.
+pub fn parse_lines<T>(s: &str) -> Result<Vec<T>>
+    where T: FromStr,
+          T::Err: Into<anyhow::Error>
+{
+    map_lines(s, str::parse)
+}
+
+/// Maps every line of `s` through `f`, collecting the results. An error that
+/// doesn't already point somewhere more specific is attached to the whole
+/// line that caused it.
+pub fn map_lines<'a, T, E, F>(s: &'a str, mut f: F) -> Result<Vec<T>>
+    where F: FnMut(&'a str) -> std::result::Result<T, E>,
+          E: Into<anyhow::Error>
+{
+    s.lines()
+        .map(|l| f(l).at(l))
+        .collect()
+}
@@ -0,0 +1,107 @@
+//! Golden-answer checks shared by every day's `tests/regression.rs`.
+//!
+//! Each day directory has an `answers.txt` with one row per input file:
+//!
+//! ```text
+//! # input          part1    part2
+//! input_test.txt   37       26
+//! input.txt        2178     1978
+//! ```
+//!
+//! `-` in an answer column means that part is not checked for that input,
+//! e.g. because the example only covers the other part.
+
+use crate::diagnostic::{self, Diagnostic};
+use crate::{solve, PartSolution, Solver};
+use anyhow::{anyhow, Context, Result};
+use std::fmt::Write;
+use std::path::Path;
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+pub struct Expected {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+pub fn read_expected(path: &Path) -> Result<Vec<Expected>> {
+    let s = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read '{}'", path.display()))?;
+
+    s.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+        .map(|(i, l)| {
+            let fields: Vec<&str> = l.split_whitespace().collect();
+            if fields.len() != 3 {
+                return Err(anyhow!("{}:{}: expected 3 columns but got {}", path.display(), i + 1, fields.len()));
+            }
+
+            let answer = |s: &str| if s == "-" { None } else { Some(s.to_string()) };
+            Ok(Expected {
+                input: fields[0].to_string(),
+                answers: [answer(fields[1]), answer(fields[2])],
+            })
+        })
+        .collect()
+}
+
+/// Solves every input listed in `dir/answers.txt` and panics with a list of
+/// every answer that differs from the expected one.
+#[track_caller]
+pub fn check<S: Solver>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let answers_path = dir.join(ANSWERS_FILE);
+    let expected = read_expected(&answers_path).unwrap_or_else(|e| panic!("{:#}", e));
+    assert!(!expected.is_empty(), "{} does not list any inputs", answers_path.display());
+
+    let mut diff = String::new();
+    for e in expected.iter() {
+        let parts: Vec<u8> = (1..=2).filter(|&p| e.answers[p as usize - 1].is_some()).collect();
+        let actual = std::fs::read_to_string(dir.join(&e.input))
+            .with_context(|| format!("could not read '{}'", e.input))
+            .and_then(|input| solve::<S>(&input, &parts))
+            .map_err(|mut err| {
+                diagnostic::set_file(&mut err, &e.input);
+                err
+            });
+
+        let actual = match actual {
+            Ok(actual) => actual,
+            Err(err) => {
+                writeln!(diff, "  {}: {:#}", e.input, err).unwrap();
+                continue;
+            },
+        };
+
+        for PartSolution { part, answer, .. } in actual.parts {
+            let expected = e.answers[part as usize - 1].as_ref().unwrap();
+            match answer {
+                Ok(answer) if &answer == expected => (),
+                Ok(answer) => writeln!(diff, "  {} part {}: expected {}, got {}", e.input, part, expected, answer).unwrap(),
+                Err(mut err) => {
+                    diagnostic::set_file(&mut err, &e.input);
+                    writeln!(diff, "  {} part {}: expected {}, got error: {:#}", e.input, part, expected, err).unwrap();
+                },
+            }
+        }
+    }
+
+    if !diff.is_empty() {
+        panic!("answers differ from {}:\n{}", answers_path.display(), diff);
+    }
+}
+
+/// The located diagnostic that parsing `input` fails with, for checking
+/// that a day's parser rejects malformed input. Panics if parsing succeeds
+/// or fails without a diagnostic.
+#[track_caller]
+pub fn parse_error<S: Solver>(input: &str) -> Diagnostic {
+    match solve::<S>(input, &[]) {
+        Ok(_) => panic!("expected {:?} not to parse", input),
+        Err(e) => match e.downcast::<Diagnostic>() {
+            Ok(d) => d,
+            Err(e) => panic!("expected a diagnostic for {:?}, got: {:#}", input, e),
+        },
+    }
+}
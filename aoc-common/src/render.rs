@@ -0,0 +1,150 @@
+//! Drawing grids as coloured terminal output and as PPM or PNG images.
+//!
+//! A day draws each state it wants to show into a [`Frame`], a grid of
+//! coloured characters, by implementing [`Render`]. The frames can then be
+//! printed with [`ansi`] or written to disk with [`save_frames`], where each
+//! cell becomes a square of pixels.
+
+use crate::diagnostic;
+use crate::grid::{Grid, ToChar};
+use crate::Solver;
+use anyhow::{anyhow, Context, Result};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// One cell of a [`Frame`]: a character for the terminal, and the colour of
+/// that character or of the cell's pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Cell {
+    /// Empty space, e.g. between the slices of a 3D grid.
+    pub const BLANK: Cell = Cell { glyph: ' ', color: Rgb(0, 0, 0) };
+
+    pub const fn new(glyph: char, color: Rgb) -> Self {
+        Cell { glyph, color }
+    }
+}
+
+impl ToChar for Cell {
+    fn to_char(&self) -> char {
+        self.glyph
+    }
+}
+
+pub type Frame = Grid<Cell>;
+
+/// A frame of `rows` by `cols` blank cells.
+pub fn blank(rows: i64, cols: i64) -> Frame {
+    Grid::new(rows, cols, vec![Cell::BLANK; (rows * cols) as usize])
+}
+
+/// A solver whose intermediate states can be drawn.
+pub trait Render: Solver {
+    /// The states `part` goes through, in order. Only called with part 1
+    /// or 2.
+    fn frames(input: &Self::Input<'_>, part: u8) -> Vec<Frame>;
+}
+
+/// [`render`] for a particular day.
+pub type Renderer = fn(&str, u8) -> Result<Vec<Frame>>;
+
+/// Parses `input` and draws the states of `part`.
+pub fn render<S: Render>(input: &str, part: u8) -> Result<Vec<Frame>> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("invalid part {}", part));
+    }
+    let parsed = S::parse(input).map_err(|e| diagnostic::locate(e, input))?;
+    Ok(S::frames(&parsed, part))
+}
+
+/// The frame as lines of 24-bit ANSI coloured characters.
+pub fn ansi(frame: &Frame) -> String {
+    let mut s = String::new();
+    for row in 0..frame.rows() {
+        for col in 0..frame.cols() {
+            let Cell { glyph, color: Rgb(r, g, b) } = frame[(row, col)];
+            s += &format!("\x1b[38;2;{};{};{}m{}", r, g, b, glyph);
+        }
+        s += "\x1b[0m\n";
+    }
+    s
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// The frame's pixels as RGB bytes, row by row, with each cell drawn as a
+/// `scale` by `scale` square.
+fn pixels(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut data = Vec::with_capacity(frame.rows() as usize * frame.cols() as usize * scale * scale * 3);
+    for row in 0..frame.rows() {
+        let mut line = Vec::with_capacity(frame.cols() as usize * scale * 3);
+        for col in 0..frame.cols() {
+            let Rgb(r, g, b) = frame[(row, col)].color;
+            for _ in 0..scale {
+                line.extend_from_slice(&[r, g, b]);
+            }
+        }
+        for _ in 0..scale {
+            data.extend_from_slice(&line);
+        }
+    }
+    data
+}
+
+/// Writes the frame as a binary (P6) PPM.
+pub fn write_ppm(frame: &Frame, scale: usize, mut w: impl Write) -> Result<()> {
+    write!(w, "P6\n{} {}\n255\n", frame.cols() as usize * scale, frame.rows() as usize * scale)?;
+    w.write_all(&pixels(frame, scale))?;
+    Ok(())
+}
+
+pub fn write_png(frame: &Frame, scale: usize, w: impl Write) -> Result<()> {
+    let mut encoder = png::Encoder::new(w, (frame.cols() as usize * scale) as u32, (frame.rows() as usize * scale) as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels(frame, scale))?;
+    Ok(())
+}
+
+/// Writes each frame to `dir/frame0000.<ext>`, `dir/frame0001.<ext>`, ...,
+/// creating `dir` if needed, and returns the paths written.
+pub fn save_frames(frames: &[Frame], dir: &Path, format: ImageFormat, scale: usize) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir).with_context(|| format!("could not create '{}'", dir.display()))?;
+
+    frames.iter()
+        .enumerate()
+        .map(|(i, frame)| {
+            let path = dir.join(format!("frame{:04}.{}", i, format.extension()));
+            let file = File::create(&path).with_context(|| format!("could not create '{}'", path.display()))?;
+            let mut w = BufWriter::new(file);
+            match format {
+                ImageFormat::Ppm => write_ppm(frame, scale, &mut w)?,
+                ImageFormat::Png => write_png(frame, scale, &mut w)?,
+            }
+            w.flush()?;
+            Ok(path)
+        })
+        .collect()
+}
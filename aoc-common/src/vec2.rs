@@ -0,0 +1,87 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: MulAssign + Copy> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl<T: Neg<Output = T> + Copy> Vec2<T> {
+    /// Rotates by `steps` quarter turns, counter-clockwise for positive
+    /// `steps` and clockwise for negative.
+    pub fn turn(&mut self, steps: i64) {
+        let positive = steps >= 0;
+        let s = steps.abs() % 4;
+
+        for _ in 0..s {
+            let (x, y) = (self.x, self.y);
+            if positive {
+                self.x = y.neg();
+                self.y = x;
+            }
+            else {
+                self.x = y;
+                self.y = x.neg();
+            }
+        }
+    }
+}
+
+impl Vec2<i64> {
+    pub fn manhattan_magnitude(&self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+}
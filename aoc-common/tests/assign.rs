@@ -0,0 +1,24 @@
+use aoc_common::assign::assign;
+use std::collections::{HashMap, HashSet};
+
+fn candidates(pairs: &[(char, &[u32])]) -> HashMap<char, HashSet<u32>> {
+    pairs.iter().map(|&(k, vs)| (k, vs.iter().copied().collect())).collect()
+}
+
+#[test]
+fn needs_backtracking() {
+    // Every key has two candidates, so the first guess can be wrong.
+    let c = candidates(&[('a', &[1, 2]), ('b', &[2, 3]), ('c', &[3, 1]), ('d', &[3, 4])]);
+    let assignment = assign(&c).unwrap();
+    assert_eq!(assignment.len(), 4);
+    assert!(assignment.iter().all(|(k, v)| c[k].contains(v)));
+    assert_eq!(assignment.values().collect::<HashSet<_>>().len(), 4);
+    assert_eq!(assignment[&'d'], 4);
+}
+
+#[test]
+fn no_assignment() {
+    assert_eq!(assign(&candidates(&[('a', &[1]), ('b', &[1])])), None);
+    assert_eq!(assign(&candidates(&[('a', &[])])), None);
+    assert_eq!(assign(&candidates(&[])), Some(HashMap::new()));
+}
@@ -0,0 +1,129 @@
+use aoc_common::combinator::{
+    complete, many1, map, number, one_of, opt, or, satisfy, separated_list1, spanned, tag, take_until, take_while1, Error, PResult,
+};
+use aoc_common::diagnostic::Diagnostic;
+
+/// The message `parser` fails on `s` with.
+fn message<'a, T>(parser: impl Fn(&'a str) -> PResult<'a, T>, s: &'a str) -> String {
+    match parser(s) {
+        Ok(_) => panic!("expected {:?} not to parse", s),
+        Err(e) => Diagnostic::from(e).message().to_string(),
+    }
+}
+
+#[test]
+fn tag_and_found_token() {
+    assert_eq!(tag("mask")("mask = 1"), Ok(("mask", " = 1")));
+    assert_eq!(message(tag("mask"), "mem[8]"), "expected 'mask', found 'mem'");
+    assert_eq!(message(tag("-"), ":3"), "expected '-', found ':'");
+    assert_eq!(message(tag("-"), ""), "expected '-', found end of line");
+    assert_eq!(message(tag("-"), "\r\n"), "expected '-', found end of line");
+}
+
+#[test]
+fn error_points_at_failure() {
+    let s = "1-3 a";
+    let e = tag(":")(&s[3..]).unwrap_err();
+    assert_eq!(e, Error::new(&s[3..], "':'"));
+
+    let mut d = Diagnostic::from(e);
+    d.locate(s);
+    assert_eq!(d.location().unwrap().column, 4);
+}
+
+#[test]
+fn one_of_lists_every_choice() {
+    const DIRECTIONS: &[(&str, char)] = &[("e", 'e'), ("se", 's'), ("sw", 'w')];
+    assert_eq!(one_of(DIRECTIONS)("sw"), Ok(('w', "")));
+    assert_eq!(message(one_of(DIRECTIONS), "nw"), "expected 'e', 'se' or 'sw', found 'nw'");
+}
+
+#[test]
+fn or_merges_failures_at_the_same_place() {
+    let sign = or(tag("+"), tag("-"));
+    assert_eq!(sign("-1"), Ok(("-", "1")));
+    assert_eq!(message(&sign, "*1"), "expected '+' or '-', found '*'");
+
+    let same = or(tag("+"), tag("+"));
+    assert_eq!(message(same, "*"), "expected '+', found '*'");
+}
+
+#[test]
+fn or_keeps_the_failure_that_got_further() {
+    let mask = |s| {
+        let (_, s) = tag("mask")(s)?;
+        tag(" = ")(s)
+    };
+    let mem = |s| {
+        let (_, s) = tag("mem[")(s)?;
+        tag("]")(s)
+    };
+    assert_eq!(message(or(mask, mem), "mask: 1"), "expected ' = ', found ':'");
+    assert_eq!(message(or(mask, mem), "mem[ = 1"), "expected ']', found ' '");
+    assert_eq!(message(or(mask, mem), "mat"), "expected 'mask' or 'mem[', found 'mat'");
+}
+
+#[test]
+fn number_and_satisfy() {
+    assert_eq!(number::<u32>("42abc"), Ok((42, "abc")));
+    assert_eq!(message(number::<u32>, "x1"), "expected a number, found 'x1'");
+    assert_eq!(message(number::<u8>, "300"), "expected a number (number too large to fit in target type), found '300'");
+    assert_eq!(satisfy("a letter", |c| c.is_ascii_lowercase())("ab"), Ok(('a', "b")));
+    assert_eq!(message(satisfy("a letter", |c| c.is_ascii_lowercase()), "1"), "expected a letter, found '1'");
+}
+
+#[test]
+fn take_while1_and_take_until() {
+    assert_eq!(take_while1("a word", |c| c.is_ascii_lowercase())("red bags"), Ok(("red", " bags")));
+    assert_eq!(message(take_while1("a word", |c| c.is_ascii_lowercase()), " bags"), "expected a word, found ' '");
+    assert_eq!(take_until(" bags")("light red bags"), Ok(("light red", " bags")));
+    assert_eq!(message(take_until(" bags"), "light red"), "expected ' bags', found end of line");
+}
+
+#[test]
+fn map_and_opt() {
+    assert_eq!(map(number::<u32>, |n| n * 2)("21"), Ok((42, "")));
+    assert_eq!(opt(tag("-"))("-1"), Ok((Some("-"), "1")));
+    assert_eq!(opt(tag("-"))("1"), Ok((None, "1")));
+}
+
+#[test]
+fn many1_needs_one_and_stops_without_progress() {
+    assert_eq!(many1(tag("ab"))("ababc"), Ok((vec!["ab", "ab"], "c")));
+    assert_eq!(message(many1(tag("ab")), "c"), "expected 'ab', found 'c'");
+    assert_eq!(many1(opt(tag("x")))("y"), Ok((vec![None], "y")));
+}
+
+#[test]
+fn separated_list1_needs_an_item_after_each_separator() {
+    let list = separated_list1(number::<u32>, tag(","));
+    assert_eq!(list("1,2,3 x"), Ok((vec![1, 2, 3], " x")));
+    assert_eq!(list("7"), Ok((vec![7], "")));
+
+    let s = "1,2,";
+    let e = list(s).unwrap_err();
+    assert_eq!(e, Error::new(&s[4..], "a number"));
+    assert_eq!(message(&list, "1,,2"), "expected a number, found ','");
+}
+
+#[test]
+fn spanned_gives_the_matched_slice() {
+    let s = "1-3 a";
+    let ((value, span), rest) = spanned(number::<u32>)(s).unwrap();
+    assert_eq!((value, span, rest), (1, "1", "-3 a"));
+    assert_eq!(span.as_ptr(), s.as_ptr());
+}
+
+#[test]
+fn complete_rejects_trailing_input() {
+    assert_eq!(complete(number::<u32>, "12").unwrap(), 12);
+
+    let s = "12 apples";
+    let e = complete(number::<u32>, s).unwrap_err();
+    let d = e.downcast_ref::<Diagnostic>().unwrap();
+    assert_eq!(d.message(), "expected end of line, found ' '");
+
+    let mut d = d.clone();
+    d.locate(s);
+    assert_eq!(d.location().unwrap().column, 3);
+}
@@ -0,0 +1,96 @@
+use aoc_common::diagnostic::{locate, Diagnostic, Location, ResultExt};
+
+fn located(input: &str, span: &str, message: &str) -> Diagnostic {
+    let mut d = Diagnostic::new(span, message);
+    d.locate(input);
+    d
+}
+
+#[test]
+fn line_and_column() {
+    let input = "FBFBBFFRLR\nBFFFBBXRRR\n";
+    let d = located(input, &input[17..18], "invalid step 'X'");
+    assert_eq!(d.location(), Some(&Location { line: 2, column: 7, len: 1, excerpt: "BFFFBBXRRR".to_string() }));
+}
+
+#[test]
+fn renders_caret_under_span() {
+    let input = "nop +0\nfoo +1\n";
+    let mut d = located(input, &input[7..10], "invalid opcode 'foo'");
+    d.set_file("day08/input.txt");
+    assert_eq!(d.to_string(), "\
+invalid opcode 'foo'
+ --> day08/input.txt:2:1
+  |
+2 | foo +1
+  | ^^^");
+}
+
+#[test]
+fn gutter_fits_line_number() {
+    let input = "1\n".repeat(9) + "1 + x\n";
+    let d = located(&input, &input[22..23], "expected a number");
+    assert_eq!(d.to_string(), "\
+expected a number
+  --> <input>:10:5
+   |
+10 | 1 + x
+   |     ^");
+}
+
+#[test]
+fn columns_count_characters() {
+    let input = "é€x\n";
+    let at = input.find('x').unwrap();
+    let d = located(input, &input[at..at + 1], "invalid character 'x'");
+    let location = d.location().unwrap();
+    assert_eq!((location.column, location.len), (3, 1));
+    assert!(d.to_string().ends_with("1 | é€x\n  |   ^"));
+}
+
+#[test]
+fn trims_carriage_returns() {
+    let input = "F10\r\nQ3\r\n";
+    let d = located(input, &input[5..6], "invalid action 'Q'");
+    let location = d.location().unwrap();
+    assert_eq!((location.line, location.column), (2, 1));
+    assert_eq!(location.excerpt, "Q3");
+}
+
+#[test]
+fn empty_span_at_end_of_input() {
+    let input = "939\n";
+    let d = located(input, &input[input.len()..], "expected the bus IDs");
+    let location = d.location().unwrap();
+    assert_eq!((location.line, location.column, location.len), (2, 1, 1));
+    assert_eq!(location.excerpt, "");
+}
+
+#[test]
+fn span_that_spans_lines_is_cut_at_line_end() {
+    let input = "ab\ncd\n";
+    let d = located(input, &input[1..5], "bad");
+    assert_eq!(d.location().unwrap().len, 1);
+}
+
+#[test]
+fn span_outside_input_is_not_located() {
+    let other = String::from("elsewhere");
+    let d = located("input\n", &other, "made up");
+    assert_eq!(d.location(), None);
+    assert_eq!(d.to_string(), "made up");
+}
+
+#[test]
+fn at_keeps_inner_diagnostics() {
+    let input = "1-3 a: abcde\n";
+    let inner: anyhow::Result<()> = Err(Diagnostic::new(&input[4..5], "inner").into());
+    let e = locate(inner.at(&input[..12]).unwrap_err(), input);
+    assert_eq!(e.downcast_ref::<Diagnostic>().unwrap().location().unwrap().column, 5);
+
+    let outer: Result<u32, _> = "x".parse::<u32>();
+    let e = locate(outer.at(&input[..3]).unwrap_err(), input);
+    let d = e.downcast_ref::<Diagnostic>().unwrap();
+    assert_eq!(d.message(), "invalid digit found in string");
+    assert_eq!(d.location().unwrap().len, 3);
+}
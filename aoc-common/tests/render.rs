@@ -0,0 +1,68 @@
+use aoc_common::grid::Grid;
+use aoc_common::render::{self, ansi, write_png, write_ppm, Cell, Frame, Rgb};
+
+/// A 1x2 frame: a red `#` then a blue `.`.
+fn frame() -> Frame {
+    Grid::new(1, 2, vec![Cell::new('#', Rgb(255, 0, 0)), Cell::new('.', Rgb(0, 0, 255))])
+}
+
+#[test]
+fn blank() {
+    let frame = render::blank(2, 3);
+    assert_eq!((frame.rows(), frame.cols()), (2, 3));
+    assert!(frame.iter().all(|(_, &cell)| cell == Cell::BLANK));
+}
+
+#[test]
+fn ansi_colors_each_cell() {
+    assert_eq!(ansi(&frame()), "\x1b[38;2;255;0;0m#\x1b[38;2;0;0;255m.\x1b[0m\n");
+}
+
+#[test]
+fn ppm() {
+    let mut out = vec![];
+    write_ppm(&frame(), 1, &mut out).unwrap();
+    assert_eq!(out, b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\xff");
+}
+
+#[test]
+fn ppm_scaled() {
+    let mut out = vec![];
+    write_ppm(&frame(), 3, &mut out).unwrap();
+    let header = b"P6\n6 3\n255\n";
+    assert!(out.starts_with(header));
+
+    // Each cell becomes a 3x3 square, so every row of pixels is three red
+    // pixels then three blue ones.
+    let pixels = &out[header.len()..];
+    assert_eq!(pixels.len(), 6 * 3 * 3);
+    let row: Vec<u8> = [[255, 0, 0].repeat(3), [0, 0, 255].repeat(3)].concat();
+    for line in pixels.chunks(6 * 3) {
+        assert_eq!(line, &row[..]);
+    }
+}
+
+#[test]
+fn png_round_trip() {
+    let mut out = vec![];
+    write_png(&frame(), 2, &mut out).unwrap();
+
+    let mut reader = png::Decoder::new(&out[..]).read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height), (4, 2));
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+
+    let mut ppm = vec![];
+    write_ppm(&frame(), 2, &mut ppm).unwrap();
+    assert_eq!(&pixels[..info.buffer_size()], &ppm[b"P6\n4 2\n255\n".len()..]);
+}
+
+#[test]
+fn save_frames_numbers_files() {
+    let dir = std::env::temp_dir().join(format!("aoc-render-test-{}", std::process::id()));
+    let paths = render::save_frames(&[frame(), frame()], &dir, render::ImageFormat::Ppm, 1).unwrap();
+    assert_eq!(paths, vec![dir.join("frame0000.ppm"), dir.join("frame0001.ppm")]);
+    assert!(std::fs::read(&paths[1]).unwrap().starts_with(b"P6\n"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
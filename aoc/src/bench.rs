@@ -0,0 +1,135 @@
+use crate::days::Day;
+use anyhow::Result;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Serialize)]
+pub struct Report {
+    pub runs: usize,
+    pub days: Vec<DayReport>,
+}
+
+#[derive(Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub stages: Vec<StageReport>,
+}
+
+#[derive(Serialize)]
+pub struct StageReport {
+    pub stage: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Stats>,
+}
+
+#[derive(Serialize, Clone, Copy)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "max_ns", serialize_with = "as_nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        }
+        else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+
+        Stats { min: samples[0], median, mean, max: samples[n - 1] }
+    }
+}
+
+pub fn as_nanos<S: serde::Serializer>(d: &Duration, s: S) -> std::result::Result<S::Ok, S::Error> {
+    s.serialize_u64(d.as_nanos() as u64)
+}
+
+/// Solves `input` `runs` times, timing parsing and each part separately.
+///
+/// A part that fails is reported as failed and not timed; a parse failure
+/// fails the whole day.
+pub fn bench_day(day: &Day, input: &str, parts: &[u8], runs: usize) -> DayReport {
+    match bench_stages(day, input, parts, runs) {
+        Ok(stages) => DayReport { day: day.number, error: None, stages },
+        Err(e) => DayReport { day: day.number, error: Some(format!("{:#}", e)), stages: vec![] },
+    }
+}
+
+fn bench_stages(day: &Day, input: &str, parts: &[u8], runs: usize) -> Result<Vec<StageReport>> {
+    let mut parse_samples = vec![];
+    let mut part_samples = vec![vec![]; parts.len()];
+    let mut part_errors = vec![None; parts.len()];
+
+    for _ in 0..runs {
+        let solution = (day.solve)(input, parts)?;
+        parse_samples.push(solution.parse_time);
+        for (i, part) in solution.parts.into_iter().enumerate() {
+            match part.answer {
+                Ok(_) => part_samples[i].push(part.time),
+                Err(e) => part_errors[i] = Some(format!("{:#}", e)),
+            }
+        }
+    }
+
+    let mut stages = vec![StageReport {
+        stage: "parse".to_string(),
+        error: None,
+        stats: Some(Stats::from_samples(parse_samples)),
+    }];
+
+    for ((part, samples), error) in parts.iter().zip(part_samples).zip(part_errors) {
+        let stats = if error.is_none() { Some(Stats::from_samples(samples)) } else { None };
+        stages.push(StageReport { stage: format!("part{}", part), error, stats });
+    }
+
+    Ok(stages)
+}
+
+pub fn print_table(report: &Report) {
+    println!("{:>3}  {:<6} {:>10} {:>10} {:>10} {:>10}", "day", "stage", "min", "median", "mean", "max");
+    for day in report.days.iter() {
+        if let Some(error) = &day.error {
+            println!("{:>3}  error: {}", day.day, error);
+        }
+
+        for stage in day.stages.iter() {
+            match (&stage.stats, &stage.error) {
+                (Some(stats), _) => println!("{:>3}  {:<6} {:>10} {:>10} {:>10} {:>10}",
+                    day.day, stage.stage,
+                    format_duration(stats.min), format_duration(stats.median),
+                    format_duration(stats.mean), format_duration(stats.max)),
+                (None, error) => println!("{:>3}  {:<6} error: {}", day.day, stage.stage, error.as_deref().unwrap_or("")),
+            }
+        }
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    }
+    else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    }
+    else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    }
+    else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
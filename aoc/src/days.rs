@@ -0,0 +1,44 @@
+use anyhow::Result;
+use aoc_common::generate::Generator;
+use aoc_common::render::{render, Renderer};
+use aoc_common::{solve, Solution};
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, &[u8]) -> Result<Solution>,
+    pub generate: Generator,
+    /// Draws a part's intermediate states, for the days that can.
+    pub render: Option<Renderer>,
+}
+
+pub const DAYS: &[Day] = &[
+    Day { number: 1, solve: solve::<day01::Day01>, generate: day01::generate::input, render: None },
+    Day { number: 2, solve: solve::<day02::Day02>, generate: day02::generate::input, render: None },
+    Day { number: 3, solve: solve::<day03::Day03>, generate: day03::generate::input, render: Some(render::<day03::Day03>) },
+    Day { number: 4, solve: solve::<day04::Day04>, generate: day04::generate::input, render: None },
+    Day { number: 5, solve: solve::<day05::Day05>, generate: day05::generate::input, render: None },
+    Day { number: 6, solve: solve::<day06::Day06>, generate: day06::generate::input, render: None },
+    Day { number: 7, solve: solve::<day07::Day07>, generate: day07::generate::input, render: None },
+    Day { number: 8, solve: solve::<day08::Day08>, generate: day08::generate::input, render: None },
+    Day { number: 9, solve: solve::<day09::Day09>, generate: day09::generate::input, render: None },
+    Day { number: 10, solve: solve::<day10::Day10>, generate: day10::generate::input, render: None },
+    Day { number: 11, solve: solve::<day11::Day11>, generate: day11::generate::input, render: Some(render::<day11::Day11>) },
+    Day { number: 12, solve: solve::<day12::Day12>, generate: day12::generate::input, render: None },
+    Day { number: 13, solve: solve::<day13::Day13>, generate: day13::generate::input, render: None },
+    Day { number: 14, solve: solve::<day14::Day14>, generate: day14::generate::input, render: None },
+    Day { number: 15, solve: solve::<day15::Day15>, generate: day15::generate::input, render: None },
+    Day { number: 16, solve: solve::<day16::Day16>, generate: day16::generate::input, render: None },
+    Day { number: 17, solve: solve::<day17::Day17>, generate: day17::generate::input, render: Some(render::<day17::Day17>) },
+    Day { number: 18, solve: solve::<day18::Day18>, generate: day18::generate::input, render: None },
+    Day { number: 19, solve: solve::<day19::Day19>, generate: day19::generate::input, render: None },
+    Day { number: 20, solve: solve::<day20::Day20>, generate: day20::generate::input, render: None },
+    Day { number: 21, solve: solve::<day21::Day21>, generate: day21::generate::input, render: None },
+    Day { number: 22, solve: solve::<day22::Day22>, generate: day22::generate::input, render: None },
+    Day { number: 23, solve: solve::<day23::Day23>, generate: day23::generate::input, render: None },
+    Day { number: 24, solve: solve::<day24::Day24>, generate: day24::generate::input, render: None },
+    Day { number: 25, solve: solve::<day25::Day25>, generate: day25::generate::input, render: None },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
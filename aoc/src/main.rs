@@ -0,0 +1,431 @@
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, Subcommand};
+use aoc_common::diagnostic;
+use aoc_common::generate;
+use aoc_common::input::InputSource;
+use aoc_common::render::{self, ImageFormat};
+use std::path::PathBuf;
+use std::time::Instant;
+use std::str::FromStr;
+
+mod bench;
+mod days;
+mod output;
+mod parallel;
+mod scaffold;
+mod serve;
+
+use aoc_common::Solution;
+use bench::{format_duration, DayReport, Report};
+use days::Day;
+use output::{DayOutput, Format, RunReport};
+use parallel::SummaryRow;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2020 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for one day, or for every day
+    Run {
+        /// Day number, or `all`
+        day: DaySelection,
+
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this file, or `-` for stdin, instead
+        /// of dayNN/input.txt in the input dir
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Also print how long parsing and each part took
+        #[arg(long)]
+        time: bool,
+
+        /// Print answers as text, or as JSON with timings for scripts
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+
+    /// Time parsing and each part over repeated runs
+    Bench {
+        /// Day number, or `all`
+        day: DaySelection,
+
+        /// Only time this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this file, or `-` for stdin, instead
+        /// of dayNN/input.txt in the input dir
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// How many times to run each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+
+        /// Also write the results as JSON to this file
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+
+    /// Draw a part's intermediate states, for days 3, 11 and 17
+    Render {
+        /// Day number
+        day: u8,
+
+        /// Draw this part's states
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Read the puzzle input from this file, or `-` for stdin, instead
+        /// of dayNN/input.txt in the input dir
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Print the frames in colour, or write them as images to --out
+        #[arg(long, value_enum, default_value_t = RenderFormat::Ansi)]
+        format: RenderFormat,
+
+        /// Directory to write images to, instead of render/dayNN
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// Width and height in pixels of each cell in images
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+    },
+
+    /// Find entries in a day 1 expense report that sum to a target
+    Sum {
+        /// How many entries to add up
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..))]
+        k: u64,
+
+        /// What the entries should sum to
+        #[arg(long, default_value_t = day01::TARGET, allow_negative_numbers = true)]
+        target: i64,
+
+        /// Read the expense report from this file, or `-` for stdin,
+        /// instead of day01/input.txt in the input dir
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Print every different combination, not just the first found
+        #[arg(long)]
+        all: bool,
+
+        /// Only print how many different combinations there are
+        #[arg(long, conflicts_with = "all")]
+        count: bool,
+    },
+
+    /// Serve solver results over HTTP; see `POST /solve`
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        addr: String,
+    },
+
+    /// Create a crate for a new day and add it to the runner
+    New {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+
+    /// Print a random puzzle input for one day
+    Gen {
+        /// Day number
+        day: u8,
+
+        /// Seed for the random generator; the same seed and size always
+        /// give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly how many records (lines, passports, rules, ...) to generate
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum RenderFormat {
+    Ansi,
+    Ppm,
+    Png,
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    One(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        let number = s.parse().with_context(|| format!("invalid day '{}'", s))?;
+        days::find(number).ok_or_else(|| anyhow!("day {} has not been solved", number))?;
+        Ok(DaySelection::One(number))
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, part, input, time, format } => {
+            let parts = selected_parts(part);
+            let inputs = selected_inputs(day, input)?;
+
+            if format == Format::Json {
+                return run_json(inputs, &parts);
+            }
+
+            if let [(day, input)] = &inputs[..] {
+                return print_day(day, input, &parts, time);
+            }
+
+            // Several days run concurrently, so print each day's answers
+            // once they are all done rather than as they come in.
+            let start = Instant::now();
+            let runs = parallel::run_all(inputs, &parts);
+            let total = start.elapsed();
+            let rows: Vec<SummaryRow> = runs.iter().map(SummaryRow::new).collect();
+
+            let mut failed = vec![];
+            for run in runs {
+                let day = run.day;
+                println!("day{:02}", day.number);
+                if run.skipped {
+                    if let Err(e) = run.solution {
+                        println!("skipped: {}", e);
+                    }
+                    continue;
+                }
+                if let Err(e) = run.solution.and_then(|solution| print_solution(day, solution, time)) {
+                    println!("error: {:#}", e);
+                    failed.push(day.number);
+                }
+            }
+            println!();
+            parallel::print_summary(&rows, total);
+
+            if failed.is_empty() {
+                Ok(())
+            }
+            else {
+                Err(anyhow!("{} day(s) failed: {:?}", failed.len(), failed))
+            }
+        },
+
+        Command::Bench { day, part, input, runs, report } => {
+            let parts = selected_parts(part);
+            let runs = runs as usize;
+
+            let mut days = vec![];
+            for (day, input) in selected_inputs(day, input)? {
+                days.push(match input.read() {
+                    Ok(input_str) => bench::bench_day(day, &input_str, &parts, runs),
+                    Err(e) => DayReport { day: day.number, error: Some(format!("{:#}", e)), stages: vec![] },
+                });
+            }
+
+            let bench_report = Report { runs, days };
+            bench::print_table(&bench_report);
+
+            if let Some(path) = report {
+                let json = serde_json::to_string_pretty(&bench_report)?;
+                std::fs::write(&path, json + "\n")
+                    .with_context(|| format!("could not write report '{}'", path.display()))?;
+            }
+            Ok(())
+        },
+
+        Command::Render { day, part, input, format, out, scale } => {
+            let day = days::find(day).ok_or_else(|| anyhow!("day {} has not been solved", day))?;
+            let input = InputSource::or_for_day(input, day.number)?;
+            let draw = day.render.ok_or_else(|| anyhow!("day {} cannot be rendered", day.number))?;
+            let input_str = input.read()?;
+            let frames = draw(&input_str, part).map_err(|mut e| {
+                diagnostic::set_file(&mut e, &input.name());
+                e
+            })?;
+
+            let image_format = match format {
+                RenderFormat::Ansi => {
+                    let frames: Vec<String> = frames.iter().map(render::ansi).collect();
+                    print!("{}", frames.join("\n"));
+                    return Ok(());
+                },
+                RenderFormat::Ppm => ImageFormat::Ppm,
+                RenderFormat::Png => ImageFormat::Png,
+            };
+            let out = out.unwrap_or_else(|| PathBuf::from(format!("render/day{:02}", day.number)));
+            let paths = render::save_frames(&frames, &out, image_format, scale as usize)?;
+            println!("wrote {} frame(s) to {}", paths.len(), out.display());
+            Ok(())
+        },
+
+        Command::Sum { k, target, input, all, count } => {
+            let input = InputSource::or_for_day(input, 1)?;
+            let input_str = input.read()?;
+            let vals = day01::parse(&input_str).map_err(|e| {
+                let mut e = diagnostic::locate(e, &input_str);
+                diagnostic::set_file(&mut e, &input.name());
+                e
+            })?;
+
+            if all || count {
+                let sums: Vec<Vec<i64>> = day01::all_sums(&vals, k as usize, target).collect();
+                if count {
+                    println!("{}", sums.len());
+                }
+                else if sums.is_empty() {
+                    return Err(anyhow!("no {} entries sum to {}", k, target));
+                }
+                else {
+                    for entries in sums.iter() {
+                        print_sum(entries, target)?;
+                    }
+                }
+                if sums.len() > 1 {
+                    eprintln!("warning: {} different combinations of {} entries sum to {}, so the answer is ambiguous", sums.len(), k, target);
+                }
+                return Ok(());
+            }
+
+            let entries = day01::find_sum(&vals, k as usize, target)
+                .ok_or_else(|| anyhow!("no {} entries sum to {}", k, target))?;
+            print_sum(&entries, target)
+        },
+
+        Command::Serve { addr } => serve::serve(&addr),
+
+        Command::New { day } => {
+            let dir = scaffold::new_day(day)?;
+            println!("created {}", dir.display());
+            println!("add the example to input_test.txt and its answers to answers.txt, then rebuild to run it");
+            Ok(())
+        },
+
+        Command::Gen { day, seed, size } => {
+            let day = days::find(day).ok_or_else(|| anyhow!("day {} has not been solved", day))?;
+            print!("{}", generate::generate(day.generate, seed, size));
+            Ok(())
+        },
+    }
+}
+
+fn print_sum(entries: &[i64], target: i64) -> Result<()> {
+    let terms: Vec<String> = entries.iter().map(|v| v.to_string()).collect();
+    println!("{} = {} (product {})", terms.join(" + "), target, day01::product(entries)?);
+    Ok(())
+}
+
+fn selected_parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn selected_inputs(day: DaySelection, input: Option<InputSource>) -> Result<Vec<(&'static Day, InputSource)>> {
+    match day {
+        DaySelection::One(number) => {
+            let day = days::find(number).unwrap();
+            Ok(vec![(day, InputSource::or_for_day(input, day.number)?)])
+        },
+        DaySelection::All => {
+            if input.is_some() {
+                return Err(anyhow!("--input can only be used with a single day"));
+            }
+
+            days::DAYS.iter()
+                .map(|day| Ok((day, InputSource::for_day(day.number)?)))
+                .collect()
+        },
+    }
+}
+
+/// Reads and solves one day, naming the input file in any diagnostics.
+fn run_day(day: &Day, input: &InputSource, parts: &[u8]) -> Result<Solution> {
+    let input_str = input.read()?;
+    solve_day(day, &input_str, &input.name(), parts)
+}
+
+/// Solves `input`, naming it `name` in any diagnostics.
+fn solve_day(day: &Day, input: &str, name: &str, parts: &[u8]) -> Result<Solution> {
+    let mut solution = (day.solve)(input, parts)
+        .map_err(|mut e| {
+            diagnostic::set_file(&mut e, name);
+            e
+        })
+        .with_context(|| format!("day {} failed", day.number))?;
+
+    for part in solution.parts.iter_mut() {
+        if let Err(e) = &mut part.answer {
+            diagnostic::set_file(e, name);
+        }
+    }
+    Ok(solution)
+}
+
+fn print_day(day: &Day, input: &InputSource, parts: &[u8], time: bool) -> Result<()> {
+    print_solution(day, run_day(day, input, parts)?, time)
+}
+
+fn print_solution(day: &Day, solution: Solution, time: bool) -> Result<()> {
+    if time {
+        println!("parse: {}", format_duration(solution.parse_time));
+    }
+
+    let mut result = Ok(());
+    for part in solution.parts {
+        match part.answer {
+            Ok(answer) if time => println!("part{} = {} ({})", part.part, answer, format_duration(part.time)),
+            Ok(answer) => println!("part{} = {}", part.part, answer),
+            Err(e) => if result.is_ok() {
+                result = Err(e.context(format!("day {} part {} failed", day.number, part.part)));
+            },
+        }
+    }
+    result
+}
+
+/// Prints a [`RunReport`] for every selected day, failing afterwards if any
+/// day or part failed so that stdout stays valid JSON.
+fn run_json(inputs: Vec<(&'static Day, InputSource)>, parts: &[u8]) -> Result<()> {
+    let days: Vec<DayOutput> = parallel::run_all(inputs, parts).into_iter()
+        .map(|run| if run.skipped {
+            DayOutput::skipped(run.day.number, run.input.to_string())
+        }
+        else {
+            DayOutput::new(run.day.number, run.input.to_string(), run.solution)
+        })
+        .collect();
+
+    let failed: Vec<u8> = days.iter().filter(|d| d.failed()).map(|d| d.day).collect();
+    println!("{}", serde_json::to_string_pretty(&RunReport { days })?);
+
+    if failed.is_empty() {
+        Ok(())
+    }
+    else {
+        Err(anyhow!("{} day(s) failed: {:?}", failed.len(), failed))
+    }
+}
@@ -0,0 +1,117 @@
+use crate::bench::as_nanos;
+use anyhow::Result;
+use aoc_common::diagnostic::Diagnostic;
+use aoc_common::Solution;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+pub struct RunReport {
+    pub days: Vec<DayOutput>,
+}
+
+#[derive(Serialize)]
+pub struct DayOutput {
+    pub day: u8,
+    pub input: String,
+    /// Set when the day wasn't run because its default input is missing.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub skipped: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostic: Option<DiagnosticOutput>,
+    #[serde(rename = "parse_ns", serialize_with = "option_as_nanos", skip_serializing_if = "Option::is_none")]
+    pub parse_time: Option<Duration>,
+    pub parts: Vec<PartOutput>,
+}
+
+#[derive(Serialize)]
+pub struct PartOutput {
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostic: Option<DiagnosticOutput>,
+    #[serde(rename = "time_ns", serialize_with = "as_nanos")]
+    pub time: Duration,
+}
+
+/// The located part of an error that is a [`Diagnostic`], so that tools
+/// can point at the input without parsing the rendered message.
+#[derive(Serialize)]
+pub struct DiagnosticOutput {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+    pub excerpt: String,
+}
+
+impl DiagnosticOutput {
+    fn from_error(e: &anyhow::Error) -> Option<Self> {
+        let diagnostic = e.downcast_ref::<Diagnostic>()?;
+        let location = diagnostic.location()?;
+        Some(DiagnosticOutput {
+            message: diagnostic.message().to_string(),
+            line: location.line,
+            column: location.column,
+            len: location.len,
+            excerpt: location.excerpt.clone(),
+        })
+    }
+}
+
+impl DayOutput {
+    /// `input` describes where the input came from, e.g. its path.
+    pub fn new(day: u8, input: String, solution: Result<Solution>) -> Self {
+        let solution = match solution {
+            Ok(solution) => solution,
+            Err(e) => return DayOutput {
+                day,
+                input,
+                skipped: false,
+                error: Some(format!("{:#}", e)),
+                diagnostic: DiagnosticOutput::from_error(&e),
+                parse_time: None,
+                parts: vec![],
+            },
+        };
+
+        let parts = solution.parts.into_iter()
+            .map(|part| {
+                let (answer, error, diagnostic) = match part.answer {
+                    Ok(answer) => (Some(answer), None, None),
+                    Err(e) => (None, Some(format!("{:#}", e)), DiagnosticOutput::from_error(&e)),
+                };
+                PartOutput { part: part.part, answer, error, diagnostic, time: part.time }
+            })
+            .collect();
+
+        DayOutput { day, input, skipped: false, error: None, diagnostic: None, parse_time: Some(solution.parse_time), parts }
+    }
+
+    /// A day that wasn't run because there is no input for it.
+    pub fn skipped(day: u8, input: String) -> Self {
+        DayOutput { day, input, skipped: true, error: None, diagnostic: None, parse_time: None, parts: vec![] }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.error.is_some() || self.parts.iter().any(|p| p.error.is_some())
+    }
+}
+
+fn option_as_nanos<S: serde::Serializer>(d: &Option<Duration>, s: S) -> std::result::Result<S::Ok, S::Error> {
+    match d {
+        Some(d) => as_nanos(d, s),
+        None => s.serialize_none(),
+    }
+}
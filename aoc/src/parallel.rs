@@ -0,0 +1,96 @@
+//! Running several days at once, for `aoc run all`.
+
+use crate::bench::format_duration;
+use crate::days::Day;
+use crate::run_day;
+use anyhow::{anyhow, Result};
+use aoc_common::input::InputSource;
+use aoc_common::Solution;
+use rayon::prelude::*;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+pub struct DayRun {
+    pub day: &'static Day,
+    pub input: InputSource,
+    pub solution: Result<Solution>,
+    /// Whether the day wasn't run because its default input is missing.
+    pub skipped: bool,
+    pub wall_time: Duration,
+}
+
+/// Runs every day on rayon's thread pool and returns the runs in the order
+/// of `inputs`. A day that panics fails on its own without taking the other
+/// days down, and a day without a file in the input dir is skipped.
+pub fn run_all(inputs: Vec<(&'static Day, InputSource)>, parts: &[u8]) -> Vec<DayRun> {
+    inputs.into_par_iter()
+        .map(|(day, input)| {
+            let start = Instant::now();
+            if input.is_missing_default() {
+                let solution = Err(anyhow!("no input at '{}'", input));
+                return DayRun { day, input, solution, skipped: true, wall_time: start.elapsed() };
+            }
+
+            let solution = catch_panic(day, || run_day(day, &input, parts));
+            DayRun { day, input, solution, skipped: false, wall_time: start.elapsed() }
+        })
+        .collect()
+}
+
+/// Runs `f`, turning a panic into an error about `day`.
+pub fn catch_panic(day: &Day, f: impl FnOnce() -> Result<Solution>) -> Result<Solution> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(anyhow!("day {} panicked: {}", day.number, panic_message(&*payload))))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    }
+    else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    }
+    else {
+        "unknown panic"
+    }
+}
+
+/// One line of the table printed after `aoc run all`.
+pub struct SummaryRow {
+    day: u8,
+    status: &'static str,
+    answers: String,
+    wall_time: Duration,
+}
+
+impl SummaryRow {
+    pub fn new(run: &DayRun) -> Self {
+        let (ok, answers) = match &run.solution {
+            Err(e) if run.skipped => {
+                return SummaryRow { day: run.day.number, status: "skipped", answers: e.to_string(), wall_time: run.wall_time };
+            },
+            Ok(solution) => {
+                let answers: Vec<String> = solution.parts.iter()
+                    .map(|part| match &part.answer {
+                        Ok(answer) => format!("part{} = {}", part.part, answer),
+                        Err(_) => format!("part{} failed", part.part),
+                    })
+                    .collect();
+                (solution.parts.iter().all(|p| p.answer.is_ok()), answers.join(", "))
+            },
+            // Just the message; diagnostics are printed in full above.
+            Err(e) => (false, format!("{:#}", e).lines().next().unwrap_or("").to_string()),
+        };
+        let status = if ok { "ok" } else { "failed" };
+        SummaryRow { day: run.day.number, status, answers, wall_time: run.wall_time }
+    }
+}
+
+pub fn print_summary(rows: &[SummaryRow], total: Duration) {
+    println!("{:>3}  {:<7} {:>10}  answers", "day", "status", "wall time");
+    for row in rows {
+        println!("{:>3}  {:<7} {:>10}  {}", row.day, row.status, format_duration(row.wall_time), row.answers);
+    }
+    println!("total: {}", format_duration(total));
+}
@@ -0,0 +1,170 @@
+//! `aoc new`: creating a crate for a new day from the files in `templates/`.
+//!
+//! The new crate gets a solver skeleton whose parts fail with "not
+//! implemented", an empty `input_test.txt` and a regression test that checks
+//! nothing until `answers.txt` is filled in. It is added to the workspace
+//! members, to this crate's dependencies and to [`crate::days::DAYS`].
+
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Each template and where it goes in the new crate.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.in")),
+    (".gitignore", include_str!("../templates/gitignore.in")),
+    ("answers.txt", include_str!("../templates/answers.txt.in")),
+    ("input_test.txt", ""),
+    ("src/main.rs", include_str!("../templates/main.rs.in")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.in")),
+    ("src/generate.rs", include_str!("../templates/generate.rs.in")),
+    ("tests/regression.rs", include_str!("../templates/regression.rs.in")),
+];
+
+/// Creates the crate for `day` in the workspace this binary was built from,
+/// and returns its directory.
+pub fn new_day(day: u8) -> Result<PathBuf> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let dir = workspace.join(format!("day{:02}", day));
+    if dir.exists() {
+        return Err(anyhow!("'{}' already exists", dir.display()));
+    }
+
+    // Work out every edit before writing anything, so that a failure leaves
+    // the workspace as it was.
+    let edits = [
+        (workspace.join("Cargo.toml"), member_day as fn(&str) -> Option<u8>, format!("    \"day{:02}\",", day)),
+        (workspace.join("aoc/Cargo.toml"), dependency_day, format!("day{:02} = {{ path = \"../day{:02}\" }}", day, day)),
+        (workspace.join("aoc/src/days.rs"), registry_day,
+            format!("    Day {{ number: {}, solve: solve::<day{:02}::Day{:02}>, generate: day{:02}::generate::input, render: None }},", day, day, day, day)),
+    ];
+    let edited = edits.iter()
+        .map(|(path, entry_day, line)| {
+            let s = fs::read_to_string(path).with_context(|| format!("could not read '{}'", path.display()))?;
+            let s = insert_entry(&s, *entry_day, day, line).with_context(|| format!("could not update '{}'", path.display()))?;
+            Ok((path, s))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, render(template, day)).with_context(|| format!("could not write '{}'", path.display()))?;
+    }
+    for (path, s) in edited {
+        fs::write(path, s).with_context(|| format!("could not write '{}'", path.display()))?;
+    }
+
+    Ok(dir)
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{nn}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Inserts `line` among the lines that `entry_day` recognizes, keeping them
+/// sorted by day.
+fn insert_entry(s: &str, entry_day: fn(&str) -> Option<u8>, day: u8, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = s.lines().collect();
+    let entries: Vec<(usize, u8)> = lines.iter()
+        .enumerate()
+        .filter_map(|(i, l)| entry_day(l).map(|d| (i, d)))
+        .collect();
+
+    if entries.iter().any(|&(_, d)| d == day) {
+        return Err(anyhow!("day {} is already listed", day));
+    }
+    let at = match entries.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => entries.last().ok_or_else(|| anyhow!("no days are listed"))?.0 + 1,
+    };
+
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// `    "day01",` in the workspace members.
+fn member_day(line: &str) -> Option<u8> {
+    line.trim().strip_prefix("\"day")?.strip_suffix("\",")?.parse().ok()
+}
+
+/// `day01 = { path = "../day01" }` in the runner's dependencies.
+fn dependency_day(line: &str) -> Option<u8> {
+    line.strip_prefix("day")?.split_once(' ')?.0.parse().ok()
+}
+
+/// `    Day { number: 1, ... },` in the runner's registry.
+fn registry_day(line: &str) -> Option<u8> {
+    line.trim().strip_prefix("Day { number: ")?.split_once(',')?.0.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matchers() {
+        assert_eq!(member_day("    \"day07\","), Some(7));
+        assert_eq!(member_day("    \"aoc-common\","), None);
+        assert_eq!(member_day("members = ["), None);
+
+        assert_eq!(dependency_day("day12 = { path = \"../day12\" }"), Some(12));
+        assert_eq!(dependency_day("anyhow = \"1.0\""), None);
+        assert_eq!(dependency_day("daydream = \"1\""), None);
+
+        assert_eq!(registry_day("    Day { number: 25, solve: solve::<day25::Day25>, render: None },"), Some(25));
+        assert_eq!(registry_day("pub struct Day {"), None);
+        assert_eq!(registry_day("    pub number: u8,"), None);
+    }
+
+    /// `insert_entry` on a file with days 1, 3 and 4 listed in it.
+    fn check_insert(s: &str, entry_day: fn(&str) -> Option<u8>, line: fn(u8) -> String) {
+        let with = |days: &[u8]| {
+            let entries: Vec<String> = days.iter().map(|&d| line(d)).collect();
+            s.replace("{}", &entries.join("\n"))
+        };
+        let before = with(&[1, 3, 4]);
+
+        assert_eq!(insert_entry(&before, entry_day, 2, &line(2)).unwrap(), with(&[1, 2, 3, 4]));
+        assert_eq!(insert_entry(&before, entry_day, 9, &line(9)).unwrap(), with(&[1, 3, 4, 9]));
+        let e = insert_entry(&before, entry_day, 3, &line(3)).unwrap_err();
+        assert_eq!(e.to_string(), "day 3 is already listed");
+    }
+
+    #[test]
+    fn insert_member() {
+        check_insert("[workspace]\nmembers = [\n    \"aoc\",\n{}\n]\n", member_day, |d| format!("    \"day{:02}\",", d));
+    }
+
+    #[test]
+    fn insert_dependency() {
+        check_insert("[dependencies]\nanyhow = \"1.0\"\n{}\n", dependency_day, |d| format!("day{:02} = {{ path = \"../day{:02}\" }}", d, d));
+    }
+
+    #[test]
+    fn insert_registry() {
+        check_insert("pub const DAYS: &[Day] = &[\n{}\n];\n", registry_day, |d| format!("    Day {{ number: {}, render: None }},", d));
+    }
+
+    /// The matchers recognize every day in the files `new_day` edits.
+    #[test]
+    fn workspace_files() {
+        let files = [
+            (include_str!("../../Cargo.toml"), member_day as fn(&str) -> Option<u8>),
+            (include_str!("../Cargo.toml"), dependency_day),
+            (include_str!("days.rs"), registry_day),
+        ];
+        for (s, entry_day) in files {
+            let days: Vec<u8> = s.lines().filter_map(entry_day).collect();
+            assert_eq!(days, (1..=25).collect::<Vec<u8>>());
+        }
+    }
+
+    #[test]
+    fn insert_without_entries() {
+        let e = insert_entry("[dependencies]\n", dependency_day, 1, "day01 = { path = \"../day01\" }").unwrap_err();
+        assert_eq!(e.to_string(), "no days are listed");
+    }
+}
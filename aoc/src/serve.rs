@@ -0,0 +1,91 @@
+//! `aoc serve`: a local HTTP API for solving puzzle inputs.
+//!
+//! `POST /solve` takes a JSON body like
+//!
+//! ```text
+//! {"day": 5, "part": 1, "input": "FBFBBFFRLR\n"}
+//! ```
+//!
+//! where `part` is optional, and responds with the same JSON as one day of
+//! `aoc run --format json`: the answers, timings, and any error with its
+//! location in the input. Parse and part failures are still `200 OK`, since
+//! the request itself was fine; malformed requests get a `400` with an
+//! `error` message.
+
+use crate::days;
+use crate::output::DayOutput;
+use crate::parallel::catch_panic;
+use crate::{selected_parts, solve_day};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// What the request's input is called in responses and diagnostics.
+const INPUT_NAME: &str = "<request>";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SolveRequest {
+    day: u8,
+    part: Option<u8>,
+    input: String,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Serves requests on `addr` until the process is killed.
+pub fn serve(addr: &str) -> Result<()> {
+    let server = Server::http(addr).map_err(|e| anyhow!("could not listen on {}: {}", addr, e))?;
+    eprintln!("listening on http://{}", server.server_addr());
+
+    for mut request in server.incoming_requests() {
+        let (status, body) = handle(&mut request);
+        let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(body + "\n")
+            .with_status_code(status)
+            .with_header(content_type);
+        if let Err(e) = request.respond(response) {
+            eprintln!("could not respond: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// The status code and JSON body to respond to `request` with.
+fn handle(request: &mut Request) -> (u16, String) {
+    if request.url() != "/solve" {
+        return error(404, format!("no such endpoint '{}', try POST /solve", request.url()));
+    }
+    if *request.method() != Method::Post {
+        return error(405, "/solve only accepts POST".to_string());
+    }
+
+    let mut body = String::new();
+    if let Err(e) = request.as_reader().read_to_string(&mut body) {
+        return error(400, format!("could not read request body: {}", e));
+    }
+
+    match solve(&body) {
+        Ok(output) => (200, serde_json::to_string_pretty(&output).unwrap()),
+        Err(e) => error(400, format!("{:#}", e)),
+    }
+}
+
+fn solve(body: &str) -> Result<DayOutput> {
+    let request: SolveRequest = serde_json::from_str(body).map_err(|e| anyhow!("invalid request: {}", e))?;
+    let day = days::find(request.day).ok_or_else(|| anyhow!("day {} has not been solved", request.day))?;
+    if let Some(part) = request.part.filter(|part| !(1..=2).contains(part)) {
+        return Err(anyhow!("invalid part {}, expected 1 or 2", part));
+    }
+
+    let parts = selected_parts(request.part);
+    let solution = catch_panic(day, || solve_day(day, &request.input, INPUT_NAME, &parts));
+    Ok(DayOutput::new(day.number, INPUT_NAME.to_string(), solution))
+}
+
+fn error(status: u16, message: String) -> (u16, String) {
+    (status, serde_json::to_string_pretty(&ErrorResponse { error: message }).unwrap())
+}
@@ -0,0 +1,94 @@
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+
+/// `aoc serve` on a free port, killed when dropped.
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start() -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--addr", "127.0.0.1:0"])
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(child.stderr.take().unwrap()).read_line(&mut line).unwrap();
+        let addr = line.trim().strip_prefix("listening on http://").unwrap().to_string();
+        Server { child, addr }
+    }
+
+    /// The status code and JSON body of the response.
+    fn request(&self, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(stream, "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            method, path, self.addr, body.len(), body).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn solves() {
+    let server = Server::start();
+    let (status, body) = server.request("POST", "/solve", r#"{"day": 5, "part": 1, "input": "FBFBBFFRLR\n"}"#);
+    assert_eq!(status, 200);
+    assert_eq!(body["day"], 5);
+    assert_eq!(body["input"], "<request>");
+    assert!(body["parse_ns"].is_u64());
+    let parts = body["parts"].as_array().unwrap();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0]["part"], 1);
+    assert_eq!(parts[0]["answer"], "357");
+    assert!(parts[0]["time_ns"].is_u64());
+}
+
+#[test]
+fn parse_errors_are_ok() {
+    let server = Server::start();
+    let (status, body) = server.request("POST", "/solve", r#"{"day": 5, "input": "FBFBBFXRLR\n"}"#);
+    assert_eq!(status, 200);
+    assert_eq!(body["diagnostic"]["line"], 1);
+    assert_eq!(body["diagnostic"]["column"], 7);
+}
+
+#[test]
+fn rejects_bad_requests() {
+    let server = Server::start();
+
+    let (status, body) = server.request("POST", "/run", "{}");
+    assert_eq!(status, 404);
+    assert_eq!(body["error"], "no such endpoint '/run', try POST /solve");
+
+    let (status, body) = server.request("GET", "/solve", "");
+    assert_eq!(status, 405);
+    assert_eq!(body["error"], "/solve only accepts POST");
+
+    let (status, body) = server.request("POST", "/solve", r#"{"day": 5, "input": "", "extra": 1}"#);
+    assert_eq!(status, 400);
+    assert!(body["error"].as_str().unwrap().starts_with("invalid request: unknown field `extra`"));
+
+    let (status, body) = server.request("POST", "/solve", r#"{"day": 5, "part": 3, "input": ""}"#);
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "invalid part 3, expected 1 or 2");
+
+    let (status, body) = server.request("POST", "/solve", r#"{"day": 26, "input": ""}"#);
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "day 26 has not been solved");
+}
@@ -0,0 +1,37 @@
+use aoc_common::generate::{lines, Rng, SliceRandom, StdRng};
+use std::collections::HashSet;
+
+/// Expense entries, including at least one pair and one trio of different
+/// amounts that sum to 2020, with about one in ten amounts repeated.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut entries = HashSet::new();
+
+    let a = rng.random_range(1..1010);
+    entries.insert(a);
+    entries.insert(2020 - a);
+
+    loop {
+        let x = rng.random_range(1..2018);
+        let y = rng.random_range(1..2019 - x);
+        let trio = [x, y, 2020 - x - y];
+        let distinct = trio[0] != trio[1] && trio[1] != trio[2] && trio[0] != trio[2];
+        if distinct && trio.iter().all(|v| *v != 1010 && !entries.contains(v)) {
+            entries.extend(trio);
+            break;
+        }
+    }
+
+    while entries.len() < size {
+        let v = rng.random_range(1..2020);
+        if v != 1010 {
+            entries.insert(v);
+        }
+    }
+
+    let mut entries: Vec<i64> = entries.into_iter().collect();
+    entries.sort();
+    let repeats: Vec<i64> = entries.iter().copied().filter(|_| rng.random_bool(0.1)).collect();
+    entries.extend(repeats);
+    entries.shuffle(rng);
+    lines(entries)
+}
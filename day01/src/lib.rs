@@ -0,0 +1,259 @@
+//! Day 1: Report Repair. Find the entries in an expense report that sum
+//! to 2020.
+
+use anyhow::{anyhow, Result};
+use aoc_common::multiset::RefCountSet;
+use aoc_common::parse::parse_lines;
+use aoc_common::Solver;
+use std::collections::{HashMap, VecDeque};
+
+pub mod generate;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> Result<i64> {
+        part2(input)
+    }
+}
+
+/// One expense report entry per line.
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+    parse_lines(input)
+}
+
+/// What the entries of an expense report should sum to.
+pub const TARGET: i64 = 2020;
+
+/// The product of the two entries that sum to 2020.
+pub fn part1(vals: &[i64]) -> Result<i64> {
+    let pair = find_sum(vals, 2, TARGET).ok_or_else(|| anyhow!("no pair sums to {}", TARGET))?;
+    product(&pair)
+}
+
+/// The product of the three entries that sum to 2020.
+pub fn part2(vals: &[i64]) -> Result<i64> {
+    let trio = find_sum(vals, 3, TARGET).ok_or_else(|| anyhow!("no trio sums to {}", TARGET))?;
+    product(&trio)
+}
+
+/// The product of `entries`, or an error if it doesn't fit in an `i64`.
+pub fn product(entries: &[i64]) -> Result<i64> {
+    entries.iter()
+        .try_fold(1i64, |p, &v| p.checked_mul(v))
+        .ok_or_else(|| anyhow!("the product of {:?} overflows", entries))
+}
+
+/// `k` entries, at different positions in `vals`, that sum to `target`.
+/// An amount that appears more than once in the report can be used as many
+/// times as it appears.
+///
+/// Pairs are looked up in a multiset, trios found by sorting and closing in
+/// from both ends, and larger groups by meeting in the middle. Entries are
+/// summed without overflowing, so a sum outside the range of an `i64` never
+/// matches.
+pub fn find_sum(vals: &[i64], k: usize, target: i64) -> Option<Vec<i64>> {
+    if k > vals.len() {
+        return None;
+    }
+
+    match k {
+        0 => (target == 0).then(Vec::new),
+        1 => vals.iter().find(|&&v| v == target).map(|&v| vec![v]),
+        2 => find_pair(vals, target).map(|(x, y)| vec![x, y]),
+        3 => find_trio(vals, target).map(|(x, y, z)| vec![x, y, z]),
+        _ => meet_in_the_middle(vals, k, target),
+    }
+}
+
+fn find_pair(vals: &[i64], target: i64) -> Option<(i64, i64)> {
+    let counts: RefCountSet<i64> = vals.iter().copied().collect();
+    vals.iter()
+        .filter_map(|&v| Some((v, target.checked_sub(v)?)))
+        .find(|&(v, partner)| counts.count(&partner) > usize::from(partner == v))
+}
+
+fn find_trio(vals: &[i64], target: i64) -> Option<(i64, i64, i64)> {
+    let mut sorted = vals.to_vec();
+    sorted.sort_unstable();
+
+    // Summed as i128 so that the comparison with the target is exact even
+    // where an i64 sum would overflow.
+    let target = target as i128;
+    for (i, &x) in sorted.iter().enumerate() {
+        let (mut lo, mut hi) = (i + 1, sorted.len().saturating_sub(1));
+        while lo < hi {
+            let sum = x as i128 + sorted[lo] as i128 + sorted[hi] as i128;
+            if sum == target {
+                return Some((x, sorted[lo], sorted[hi]));
+            }
+            else if sum < target {
+                lo += 1;
+            }
+            else {
+                hi -= 1;
+            }
+        }
+    }
+    None
+}
+
+/// Splits each group of `k` positions into its first `k / 2` and the rest.
+/// Every sum of a first half is remembered with the half that ends
+/// earliest, so a second half only needs to look up the sum it is missing
+/// and check that the half found ends before it starts.
+fn meet_in_the_middle(vals: &[i64], k: usize, target: i64) -> Option<Vec<i64>> {
+    // Summed as i128, so that a half can overflow an i64 when the whole
+    // doesn't.
+    let sum = |positions: &[usize]| positions.iter().map(|&i| vals[i] as i128).sum::<i128>();
+
+    let mut firsts: HashMap<i128, Vec<usize>> = HashMap::new();
+    for_each_combination(vals.len(), k / 2, &mut |first| {
+        let earliest = firsts.entry(sum(first)).or_insert_with(|| first.to_vec());
+        if first.last() < earliest.last() {
+            *earliest = first.to_vec();
+        }
+        None::<()>
+    });
+
+    for_each_combination(vals.len(), k - k / 2, &mut |second| {
+        let first = firsts.get(&(target as i128 - sum(second)))?;
+        if first.last() < second.first() {
+            Some(first.iter().chain(second.iter()).map(|&i| vals[i]).collect())
+        }
+        else {
+            None
+        }
+    })
+}
+
+/// Calls `f` with each increasing list of `r` positions below `n`, in
+/// lexicographic order, until it returns something.
+fn for_each_combination<T>(n: usize, r: usize, f: &mut impl FnMut(&[usize]) -> Option<T>) -> Option<T> {
+    fn go<T>(n: usize, r: usize, start: usize, chosen: &mut Vec<usize>, f: &mut impl FnMut(&[usize]) -> Option<T>) -> Option<T> {
+        if chosen.len() == r {
+            return f(chosen);
+        }
+        for i in start..n {
+            chosen.push(i);
+            let found = go(n, r, i + 1, chosen, f);
+            chosen.pop();
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+
+    go(n, r, 0, &mut Vec::with_capacity(r), f)
+}
+
+/// Every different way to pick `k` entries that sum to `target`, each
+/// given once with its entries in ascending order. Picks that only differ
+/// in which copy of a repeated amount they use count as the same.
+///
+/// All but the last two entries are tried in turn, skipping amounts already
+/// tried in the same place, and the last two are found by closing in from
+/// both ends.
+pub fn all_sums(vals: &[i64], k: usize, target: i64) -> Sums {
+    let mut sorted = vals.to_vec();
+    sorted.sort_unstable();
+
+    let mut sums = Sums { sorted, target, prefix: None, found: VecDeque::new() };
+    if k < 2 {
+        // Too few entries to have a last two.
+        let found = match k {
+            0 => (target == 0).then(Vec::new),
+            _ => sums.sorted.contains(&target).then(|| vec![target]),
+        };
+        sums.found.extend(found);
+    }
+    else if sums.sorted.len() >= k {
+        sums.prefix = Some((0..k - 2).collect());
+    }
+    sums
+}
+
+/// The combinations of entries from [`all_sums`].
+pub struct Sums {
+    sorted: Vec<i64>,
+    target: i64,
+    /// Positions in `sorted` of the entries before the last two, still to
+    /// be tried, or `None` once every one has been.
+    prefix: Option<Vec<usize>>,
+    found: VecDeque<Vec<i64>>,
+}
+
+impl Sums {
+    /// Queues up every choice of the last two entries that completes
+    /// `prefix`.
+    fn complete_prefix(&mut self, prefix: &[usize]) {
+        let a = &self.sorted;
+        let chosen: Vec<i64> = prefix.iter().map(|&p| a[p]).collect();
+        // In i128, like find_sum, so that nothing overflows.
+        let rest = self.target as i128 - chosen.iter().map(|&v| v as i128).sum::<i128>();
+
+        let (mut lo, mut hi) = (prefix.last().map_or(0, |&p| p + 1), a.len() - 1);
+        while lo < hi {
+            let sum = a[lo] as i128 + a[hi] as i128;
+            if sum == rest {
+                self.found.push_back(chosen.iter().copied().chain([a[lo], a[hi]].iter().copied()).collect());
+                while lo < hi && a[lo + 1] == a[lo] {
+                    lo += 1;
+                }
+                lo += 1;
+                hi -= 1;
+            }
+            else if sum < rest {
+                lo += 1;
+            }
+            else {
+                hi -= 1;
+            }
+        }
+    }
+
+    /// The next prefix after `prefix` in order, with a different amount in
+    /// some place, and room left for the last two entries.
+    fn next_prefix(&self, mut prefix: Vec<usize>) -> Option<Vec<usize>> {
+        let len = prefix.len();
+        for j in (0..len).rev() {
+            let next = (prefix[j]..self.sorted.len()).find(|&q| self.sorted[q] != self.sorted[prefix[j]]);
+            if let Some(next) = next.filter(|next| next + (len - j) + 1 < self.sorted.len()) {
+                for (i, p) in prefix[j..].iter_mut().enumerate() {
+                    *p = next + i;
+                }
+                return Some(prefix);
+            }
+        }
+        None
+    }
+}
+
+impl Iterator for Sums {
+    type Item = Vec<i64>;
+
+    fn next(&mut self) -> Option<Vec<i64>> {
+        loop {
+            if let Some(sum) = self.found.pop_front() {
+                return Some(sum);
+            }
+
+            let prefix = self.prefix.take()?;
+            self.complete_prefix(&prefix);
+            self.prefix = self.next_prefix(prefix);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day01::{all_sums, find_sum, generate, Day01};
+use std::collections::BTreeSet;
+use proptest::prelude::*;
+
+fn pair_products(vals: &[i64]) -> Vec<i64> {
+    let mut products = vec![];
+    for (i, x) in vals.iter().enumerate() {
+        for y in vals[i + 1..].iter() {
+            if x + y == 2020 {
+                products.push(x * y);
+            }
+        }
+    }
+    products
+}
+
+fn trio_products(vals: &[i64]) -> Vec<i64> {
+    let mut products = vec![];
+    for (i, x) in vals.iter().enumerate() {
+        for (j, y) in vals.iter().enumerate().skip(i + 1) {
+            for z in vals[j + 1..].iter() {
+                if x + y + z == 2020 {
+                    products.push(x * y * z);
+                }
+            }
+        }
+    }
+    products
+}
+
+/// Whether some `k` entries at different positions sum to `target`.
+fn any_sum(vals: &[i64], k: usize, target: i64) -> bool {
+    match vals.split_first() {
+        _ if k == 0 => target == 0,
+        None => false,
+        Some((&v, rest)) => any_sum(rest, k - 1, target - v) || any_sum(rest, k, target),
+    }
+}
+
+/// Every different sorted list of `k` entries at different positions that
+/// sums to `target`.
+fn every_sum(vals: &[i64], k: usize, target: i64) -> BTreeSet<Vec<i64>> {
+    match vals.split_first() {
+        _ if k == 0 => (target == 0).then(Vec::new).into_iter().collect(),
+        None => BTreeSet::new(),
+        Some((&v, rest)) => {
+            let mut sums: BTreeSet<Vec<i64>> = every_sum(rest, k - 1, target - v).into_iter()
+                .map(|mut sum| {
+                    sum.push(v);
+                    sum.sort_unstable();
+                    sum
+                })
+                .collect();
+            sums.extend(every_sum(rest, k, target));
+            sums
+        },
+    }
+}
+
+/// Whether `entries` can be picked from `vals` without reusing a position.
+fn is_drawn_from(entries: &[i64], vals: &[i64]) -> bool {
+    let mut left = vals.to_vec();
+    entries.iter().all(|e| match left.iter().position(|v| v == e) {
+        Some(i) => {
+            left.swap_remove(i);
+            true
+        },
+        None => false,
+    })
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let vals = Day01::parse(&input).unwrap();
+        let rendered: String = vals.iter().map(|v| format!("{}\n", v)).collect();
+        prop_assert_eq!(rendered, input);
+    }
+
+    #[test]
+    fn matches_brute_force(seed: u64, size in 0usize..50) {
+        let vals = Day01::parse(&generate::input(&mut rng(seed), size)).unwrap();
+        prop_assert!(pair_products(&vals).contains(&Day01::part1(&vals).unwrap()));
+        prop_assert!(trio_products(&vals).contains(&Day01::part2(&vals).unwrap()));
+    }
+
+    #[test]
+    fn finds_any_k_sum(seed: u64, size in 0usize..16, k in 1usize..7, target in 0i64..6000) {
+        let vals = Day01::parse(&generate::input(&mut rng(seed), size)).unwrap();
+        match find_sum(&vals, k, target) {
+            Some(entries) => {
+                prop_assert_eq!(entries.len(), k);
+                prop_assert_eq!(entries.iter().sum::<i64>(), target);
+                prop_assert!(is_drawn_from(&entries, &vals));
+            },
+            None => prop_assert!(!any_sum(&vals, k, target)),
+        }
+    }
+
+    #[test]
+    fn lists_every_sum_once(seed: u64, size in 0usize..16, k in 0usize..6, target in 0i64..6000) {
+        let vals = Day01::parse(&generate::input(&mut rng(seed), size)).unwrap();
+        let sums: Vec<Vec<i64>> = all_sums(&vals, k, target).collect();
+        let expected = every_sum(&vals, k, target);
+        prop_assert_eq!(sums.len(), expected.len());
+        prop_assert_eq!(sums.into_iter().collect::<BTreeSet<_>>(), expected);
+    }
+
+    #[test]
+    fn lists_repeated_amounts_once(vals in prop::collection::vec(0i64..8, 0..12), k in 0usize..5, target in 0i64..20) {
+        let sums: Vec<Vec<i64>> = all_sums(&vals, k, target).collect();
+        let expected = every_sum(&vals, k, target);
+        prop_assert_eq!(sums.len(), expected.len());
+        prop_assert_eq!(sums.into_iter().collect::<BTreeSet<_>>(), expected);
+    }
+}
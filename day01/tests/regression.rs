@@ -0,0 +1,55 @@
+use aoc_common::regression::parse_error;
+use aoc_common::Solver;
+use day01::{all_sums, find_sum, Day01};
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day01>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn repeated_entries() {
+    let vals = Day01::parse("1010\n5\n1010\n").unwrap();
+    assert_eq!(Day01::part1(&vals).unwrap(), 1010 * 1010);
+    assert_eq!(find_sum(&[500, 500, 500, 520], 4, 2020), Some(vec![500, 500, 500, 520]));
+    assert_eq!(find_sum(&[673, 673, 674, 1], 3, 2020), Some(vec![673, 673, 674]));
+
+    let vals = Day01::parse("1010\n5\n").unwrap();
+    assert!(Day01::part1(&vals).is_err());
+    assert!(Day01::part2(&vals).is_err());
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day01>("12\n1x\n");
+    assert_eq!(d.message(), "invalid digit found in string");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 1)));
+}
+
+#[test]
+fn more_entries_than_the_report() {
+    assert_eq!(find_sum(&[1, 2, 3], 4, 6), None);
+    assert_eq!(find_sum(&[1, 2, 3], usize::MAX, 6), None);
+    assert_eq!(find_sum(&[], 1, 0), None);
+}
+
+#[test]
+fn overflowing_sums_never_match() {
+    let max = i64::MAX;
+    assert_eq!(find_sum(&[5, max], 2, i64::MIN + 4), None);
+    assert_eq!(find_sum(&[-5, i64::MIN], 2, max - 4), None);
+    assert_eq!(find_sum(&[1, 5, max], 3, i64::MIN + 5), None);
+    assert_eq!(find_sum(&[1, 2, 5, max], 4, i64::MIN + 7), None);
+    assert_eq!(find_sum(&[max, -1, 1, -max], 4, 0), Some(vec![max, -1, 1, -max]));
+    assert_eq!(find_sum(&[max, 1, -1, -max], 4, 0), Some(vec![max, 1, -1, -max]));
+    assert_eq!(find_sum(&[max, max, -max, 3], 3, max), Some(vec![-max, max, max]));
+}
+
+#[test]
+fn overflowing_sums_are_not_listed() {
+    let max = i64::MAX;
+    assert_eq!(all_sums(&[5, max], 2, i64::MIN + 4).count(), 0);
+    assert_eq!(all_sums(&[1, 5, max], 3, i64::MIN + 5).count(), 0);
+    assert_eq!(all_sums(&[max, max, -max, 3], 3, max).collect::<Vec<_>>(), vec![vec![-max, max, max]]);
+    assert_eq!(all_sums(&[max, 1, -1, -max], 4, 0).collect::<Vec<_>>(), vec![vec![-max, -1, 1, max]]);
+}
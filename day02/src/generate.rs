@@ -0,0 +1,16 @@
+use aoc_common::generate::{lines, word, Rng, StdRng};
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Policies and passwords, with passwords biased towards their policy's
+/// letter so that both valid and invalid ones are common.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let n1 = rng.random_range(1..=5);
+        let n2 = rng.random_range(n1..=n1 + 10);
+        let letter = word(rng, 1, LETTERS);
+        let len = rng.random_range(1..=20);
+        let password = word(rng, len, &(letter.repeat(4) + LETTERS));
+        format!("{}-{} {}: {}", n1, n2, letter, password)
+    }))
+}
@@ -0,0 +1,100 @@
+//! Day 2: Password Philosophy. Check passwords against the policies they
+//! were created under.
+
+use anyhow::Result;
+use std::fmt;
+use aoc_common::combinator::{complete, number, satisfy, tag, take_while1, PResult};
+use aoc_common::parse::map_lines;
+use aoc_common::Solver;
+
+pub mod generate;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input<'a> = Vec<Password<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Password<'_>>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Password<'_>>) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Password<'_>>) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
+/// One password per line, like `1-3 a: abcde`.
+pub fn parse(input: &str) -> Result<Vec<Password<'_>>> {
+    map_lines(input, Password::parse)
+}
+
+/// How many passwords are valid under the sled rental place's policy.
+pub fn part1(input: &[Password]) -> usize {
+    input.iter().filter(|p| p.is_valid_part1()).count()
+}
+
+/// How many passwords are valid under the Toboggan Corporate policy.
+pub fn part2(input: &[Password]) -> usize {
+    input.iter().filter(|p| p.is_valid_part2()).count()
+}
+
+/// A password and the policy it was created under.
+pub struct Password<'a> {
+    pub password: &'a str,
+    pub policy: Policy,
+}
+
+/// A letter and two numbers whose meaning depends on the part.
+pub struct Policy {
+    pub letter: char,
+    pub n1: usize,
+    pub n2: usize,
+}
+
+impl<'a> Password<'a> {
+    /// Parses a line like `1-3 a: abcde`.
+    pub fn parse(s: &'a str) -> Result<Self> {
+        complete(Self::line, s)
+    }
+
+    fn line(s: &'a str) -> PResult<'a, Self> {
+        let (n1, s) = number(s)?;
+        let (_, s) = tag("-")(s)?;
+        let (n2, s) = number(s)?;
+        let (_, s) = tag(" ")(s)?;
+        let (letter, s) = satisfy("a letter", |c| c.is_ascii_lowercase())(s)?;
+        let (_, s) = tag(": ")(s)?;
+        let (password, s) = take_while1("a password", |c| c.is_ascii_lowercase())(s)?;
+
+        let policy = Policy { letter, n1, n2 };
+
+        Ok((Password { password, policy }, s))
+    }
+
+    /// Whether `letter` appears between `n1` and `n2` times.
+    pub fn is_valid_part1(&self) -> bool {
+        let count = self.password.chars().filter(|&c| c == self.policy.letter).count();
+        (self.policy.n1..=self.policy.n2).contains(&count)
+    }
+
+    /// Whether `letter` is at exactly one of the 1-based positions `n1`
+    /// and `n2`.
+    pub fn is_valid_part2(&self) -> bool {
+        let count = [self.policy.n1, self.policy.n2].iter()
+            .filter(|&&n| n.checked_sub(1).and_then(|i| self.password.chars().nth(i)) == Some(self.policy.letter))
+            .count();
+        count == 1
+    }
+}
+
+impl fmt::Display for Password<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}: {}", self.policy.n1, self.policy.n2, self.policy.letter, self.password)
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day02::{generate, Day02};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let passwords = Day02::parse(&input).unwrap();
+        let rendered: String = passwords.iter().map(|p| format!("{}\n", p)).collect();
+        prop_assert_eq!(rendered, input);
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::regression::parse_error;
+use day02::Day02;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day02>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day02>("1-3 a: abcde\n1:3 b: cdefg\n");
+    assert_eq!(d.message(), "expected '-', found ':'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 2)));
+
+    let d = parse_error::<Day02>("1-3 ab: abcde\n");
+    assert_eq!(d.message(), "expected ': ', found 'b'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 6)));
+
+    let d = parse_error::<Day02>("1-3 a: \n");
+    assert_eq!(d.message(), "expected a password, found end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 8)));
+}
@@ -0,0 +1,7 @@
+use aoc_common::generate::{grid, Rng, StdRng};
+
+/// A map with `size` rows of open squares and trees.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let cols = rng.random_range(1..=31);
+    grid(rng, size.max(1), cols, "...#")
+}
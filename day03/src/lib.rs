@@ -0,0 +1,112 @@
+//! Day 3: Toboggan Trajectory. Count the trees hit sledding down a slope.
+
+use anyhow::{anyhow, Result};
+use aoc_common::Solver;
+use aoc_common::grid::{FromChar, Grid, ToChar};
+use aoc_common::render::{self, Cell, Frame, Render, Rgb};
+
+pub mod generate;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input<'a> = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Map> {
+        parse(input)
+    }
+
+    fn part1(input: &Map) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Map) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
+impl Render for Day03 {
+    fn frames(input: &Map, part: u8) -> Vec<Frame> {
+        let slopes: &[(usize, usize)] = if part == 1 { &PART1_SLOPES } else { &PART2_SLOPES };
+        slopes.iter().map(|slope| draw_path(slope, input)).collect()
+    }
+}
+
+const PART1_SLOPES: [(usize, usize); 1] = [(3, 1)];
+const PART2_SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+/// A map of `.` open squares and `#` trees.
+pub fn parse(input: &str) -> Result<Map> {
+    input.parse()
+}
+
+/// The trees hit going right 3, down 1.
+pub fn part1(input: &Map) -> usize {
+    count_trees(&PART1_SLOPES[0], input)
+}
+
+/// The product of the trees hit on each of five slopes.
+pub fn part2(input: &Map) -> usize {
+    PART2_SLOPES.iter().map(|s| count_trees(s, input)).product()
+}
+
+/// The trees hit going right `slope.0` and down `slope.1` at a time from
+/// the top left. The map repeats to the right.
+pub fn count_trees(slope: &(usize, usize), map: &Map) -> usize {
+    let loc_iter = (0..map.rows()).step_by(slope.1).zip((0..).step_by(slope.0));
+    loc_iter.filter(|&(row, col)| map[(row, col % map.cols())] == Square::Tree).count()
+}
+
+/// The map repeated as far right as the path along `slope` goes, with the
+/// open squares on the path as `O` and the trees hit as `X`.
+pub fn draw_path(slope: &(usize, usize), map: &Map) -> Frame {
+    let steps = (map.rows() as usize - 1) / slope.1;
+    let cols = (map.cols() as usize).max(steps * slope.0 + 1) as i64;
+    let mut frame = render::blank(map.rows(), cols);
+    for (row, col) in frame.coords() {
+        frame[(row, col)] = match map[(row, col % map.cols())] {
+            Square::Open => Cell::new('.', Rgb(60, 60, 60)),
+            Square::Tree => Cell::new('#', Rgb(30, 110, 40)),
+        };
+    }
+
+    for step in 0..=steps {
+        let (row, col) = ((step * slope.1) as i64, (step * slope.0) as i64);
+        frame[(row, col)] = match map[(row, col % map.cols())] {
+            Square::Open => Cell::new('O', Rgb(230, 230, 230)),
+            Square::Tree => Cell::new('X', Rgb(230, 40, 40)),
+        };
+    }
+    frame
+}
+
+pub type Map = Grid<Square>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Open,
+    Tree,
+}
+
+impl FromChar for Square {
+    type Err = anyhow::Error;
+
+    fn from_char(c: &char) -> Result<Self> {
+        match c {
+            '.' => Ok(Square::Open),
+            '#' => Ok(Square::Tree),
+            _   => Err(anyhow!("invalid symbol {}", c)),
+        }
+    }
+}
+
+impl ToChar for Square {
+    fn to_char(&self) -> char {
+        match self {
+            Square::Open => '.',
+            Square::Tree => '#',
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day03::{generate, Day03};
+use proptest::prelude::*;
+
+fn count_trees(input: &str, right: usize, down: usize) -> usize {
+    input.lines()
+        .step_by(down)
+        .enumerate()
+        .filter(|(i, line)| line.as_bytes()[i * right % line.len()] == b'#')
+        .count()
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 1usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        prop_assert_eq!(Day03::parse(&input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn matches_counting_on_the_text(seed: u64, size in 1usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let map = Day03::parse(&input).unwrap();
+
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+        let product: usize = slopes.iter().map(|&(right, down)| count_trees(&input, right, down)).product();
+        prop_assert_eq!(Day03::part1(&map).unwrap(), count_trees(&input, 3, 1));
+        prop_assert_eq!(Day03::part2(&map).unwrap(), product);
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::Solver;
+use aoc_common::render::render;
+use day03::Day03;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day03>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn empty_rows() {
+    assert!(Day03::parse("\n").is_err());
+    assert!(Day03::parse("\n..#\n").is_err());
+    assert!(Day03::parse("..#\n\n..#\n").is_err());
+}
+
+#[test]
+fn frames() {
+    let example = include_str!("../input_test.txt");
+    assert_eq!(render::<Day03>(example, 1).unwrap().len(), 1);
+
+    // The path down 1 and right 7 needs the map repeated to 71 columns.
+    let frames = render::<Day03>(example, 2).unwrap();
+    assert_eq!(frames.len(), 5);
+    assert_eq!((frames[3].rows(), frames[3].cols()), (11, 71));
+
+    // Going right 3, down 1 starts on open ground and hits a tree in the
+    // third row and in the last, where the map has repeated twice.
+    let frame = &frames[1];
+    assert_eq!(frame[(0, 0)].glyph, 'O');
+    assert_eq!(frame[(2, 6)].glyph, 'X');
+    assert_eq!(frame[(10, 30)].glyph, 'X');
+    assert_eq!(frame[(10, 29)].glyph, '.');
+    assert_eq!(frame.iter().filter(|(_, c)| c.glyph == 'X').count(), 7);
+}
@@ -0,0 +1,69 @@
+use aoc_common::generate::{word, IndexedRandom, Rng, SliceRandom, StdRng};
+
+const KEYS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// `size` passports. Most fields are present and valid, so that every
+/// combination of missing and invalid fields shows up.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let passports: Vec<String> = (0..size).map(|_| passport(rng)).collect();
+    passports.join("\n")
+}
+
+fn passport(rng: &mut StdRng) -> String {
+    let mut fields = vec![];
+    for key in KEYS.iter() {
+        if rng.random_bool(0.9) {
+            let valid = rng.random_bool(0.9);
+            fields.push(format!("{}:{}", key, value(rng, key, valid)));
+        }
+    }
+    if fields.is_empty() {
+        fields.push(format!("cid:{}", value(rng, "cid", true)));
+    }
+    fields.shuffle(rng);
+
+    let mut s = String::new();
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            s.push(if rng.random_bool(0.3) { '\n' } else { ' ' });
+        }
+        s.push_str(field);
+    }
+    s + "\n"
+}
+
+fn value(rng: &mut StdRng, key: &str, valid: bool) -> String {
+    let year = |rng: &mut StdRng, low: i64, high: i64| {
+        if valid {
+            rng.random_range(low..=high)
+        }
+        else if rng.random_bool(0.5) {
+            rng.random_range(low - 20..low)
+        }
+        else {
+            rng.random_range(high + 1..=high + 20)
+        }
+    };
+
+    match (key, valid) {
+        ("byr", _) => year(rng, 1920, 2002).to_string(),
+        ("iyr", _) => year(rng, 2010, 2020).to_string(),
+        ("eyr", _) => year(rng, 2020, 2030).to_string(),
+        ("hgt", true) => match rng.random_bool(0.5) {
+            true => format!("{}cm", rng.random_range(150..=193)),
+            false => format!("{}in", rng.random_range(59..=76)),
+        },
+        ("hgt", false) => ["190in", "60cm", "170", "149cm", "77in"].choose(rng).unwrap().to_string(),
+        ("hcl", true) => format!("#{}", word(rng, 6, "0123456789abcdef")),
+        ("hcl", false) => ["#12345z", "123abc", "#1234567", "#abc"].choose(rng).unwrap().to_string(),
+        ("ecl", true) => EYE_COLORS.choose(rng).unwrap().to_string(),
+        ("ecl", false) => ["xyz", "blue", "gr"].choose(rng).unwrap().to_string(),
+        ("pid", true) => word(rng, 9, "0123456789"),
+        ("pid", false) => {
+            let len = *[8, 10].choose(rng).unwrap();
+            word(rng, len, "0123456789")
+        },
+        _ => rng.random_range(1..1000).to_string(),
+    }
+}
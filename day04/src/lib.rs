@@ -0,0 +1,176 @@
+//! Day 4: Passport Processing. Check that passports have the required
+//! fields, and then that the fields have valid values.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::fmt;
+use aoc_common::combinator::{complete, number, one_of, tag, take_while1, PResult};
+use aoc_common::Solver;
+
+pub mod generate;
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input<'a> = Vec<PassportBuilder<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<PassportBuilder<'_>>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<PassportBuilder<'_>>) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<PassportBuilder<'_>>) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
+/// Passports of `key:value` fields, separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<PassportBuilder<'_>>> {
+    let mut passports = vec![];
+
+    let mut p = PassportBuilder::new();
+    for word in input.split(&[' ', '\n'][..]) {
+        if word.is_empty() {
+            if !p.fields.is_empty() {
+                passports.push(p);
+            }
+
+            p = PassportBuilder::new();
+        }
+        else {
+            p.add(word)?;
+        }
+    }
+
+    if !p.fields.is_empty() {
+        passports.push(p);
+    }
+
+    Ok(passports)
+}
+
+/// How many passports have every required field.
+pub fn part1(input: &[PassportBuilder]) -> usize {
+    input.iter().filter(|p| p.is_valid()).count()
+}
+
+/// How many passports have every required field with a valid value.
+pub fn part2(input: &[PassportBuilder]) -> usize {
+    input.iter().filter(|p| p.is_valid_part2()).count()
+}
+
+/// A passport field. Every field but `Cid` is required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Field {
+    Byr,
+    Iyr,
+    Eyr,
+    Hgt,
+    Hcl,
+    Ecl,
+    Pid,
+    Cid,
+}
+
+impl Field {
+    /// The field's key, e.g. `byr`.
+    pub fn name(&self) -> &'static str {
+        use Field::*;
+        match self {
+            Byr => "byr",
+            Iyr => "iyr",
+            Eyr => "eyr",
+            Hgt => "hgt",
+            Hcl => "hcl",
+            Ecl => "ecl",
+            Pid => "pid",
+            Cid => "cid",
+        }
+    }
+
+    /// Whether `value` is valid for this field.
+    pub fn is_valid(&self, value: &str) -> bool {
+        use Field::*;
+        match self {
+            Byr => value.parse::<i64>().is_ok_and(|v| (1920..=2002).contains(&v)),
+            Iyr => value.parse::<i64>().is_ok_and(|v| (2010..=2020).contains(&v)),
+            Eyr => value.parse::<i64>().is_ok_and(|v| (2020..=2030).contains(&v)),
+            Hgt => complete(height, value).is_ok_and(|(num, bounds)| bounds.contains(&num)),
+            Hcl => complete(hair_color, value).is_ok_and(|color| color.len() == 6),
+            Ecl => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+            Pid => complete(take_while1("a digit", |c| c.is_ascii_digit()), value).is_ok_and(|pid| pid.len() == 9),
+            Cid => true,
+        }
+    }
+}
+
+/// A height like `180cm`, and the heights allowed in its unit.
+fn height(s: &str) -> PResult<'_, (u32, RangeInclusive<u32>)> {
+    let (num, s) = number(s)?;
+    let (bounds, s) = one_of(&[("cm", (150, 193)), ("in", (59, 76))])(s)?;
+    Ok(((num, bounds.0..=bounds.1), s))
+}
+
+/// The hex digits of a color like `#123abc`.
+fn hair_color(s: &str) -> PResult<'_, &str> {
+    let (_, s) = tag("#")(s)?;
+    take_while1("a hex digit", |c| matches!(c, '0'..='9' | 'a'..='f'))(s)
+}
+
+/// The fields of a passport, which may be missing some or have invalid
+/// values.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PassportBuilder<'a> {
+    pub fields: HashMap<Field, &'a str>,
+}
+
+impl<'a> PassportBuilder<'a> {
+    pub fn new() -> Self {
+        let fields: HashMap<_, _> = Default::default();
+        PassportBuilder { fields }
+    }
+
+    /// Adds a `key:value` field.
+    pub fn add(&mut self, word: &'a str) -> Result<()> {
+        use Field::*;
+        let fields = one_of(&[
+            ("byr", Byr), ("iyr", Iyr), ("eyr", Eyr), ("hgt", Hgt),
+            ("hcl", Hcl), ("ecl", Ecl), ("pid", Pid), ("cid", Cid),
+        ]);
+        let (field, value) = fields(word)?;
+        let (_, value) = tag(":")(value)?;
+
+        self.fields.insert(field, value);
+        Ok(())
+    }
+
+    /// Whether every required field is present.
+    pub fn is_valid(&self) -> bool {
+        use Field::*;
+        let required_fields = [Byr, Iyr, Eyr, Hgt, Hcl, Ecl, Pid];
+        required_fields.iter().all(|f| self.fields.contains_key(f))
+    }
+
+    /// Whether every required field is present and valid.
+    pub fn is_valid_part2(&self) -> bool {
+        use Field::*;
+        let required_fields = [Byr, Iyr, Eyr, Hgt, Hcl, Ecl, Pid];
+        required_fields.iter().all(|f| self.fields.get(f).is_some_and(|v| f.is_valid(v)))
+    }
+}
+
+/// Writes the fields on one line, in a fixed order.
+impl fmt::Display for PassportBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields: Vec<_> = self.fields.iter().collect();
+        fields.sort();
+        let fields: Vec<String> = fields.iter().map(|(field, value)| format!("{}:{}", field.name(), value)).collect();
+        write!(f, "{}", fields.join(" "))
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day04::{generate, Day04};
+use proptest::prelude::*;
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn passports(input: &str) -> Vec<Vec<(&str, &str)>> {
+    input.split("\n\n")
+        .map(|p| p.split_whitespace().map(|field| field.split_once(':').unwrap()).collect())
+        .collect()
+}
+
+fn is_valid(key: &str, value: &str) -> bool {
+    let number_in = |low: u32, high: u32| value.parse().is_ok_and(|n: u32| low <= n && n <= high);
+    match key {
+        "byr" => number_in(1920, 2002),
+        "iyr" => number_in(2010, 2020),
+        "eyr" => number_in(2020, 2030),
+        "hgt" => match value.split_at(value.len().saturating_sub(2)) {
+            (n, "cm") => n.parse().is_ok_and(|n: u32| (150..=193).contains(&n)),
+            (n, "in") => n.parse().is_ok_and(|n: u32| (59..=76).contains(&n)),
+            _ => false,
+        },
+        "hcl" => value.len() == 7 && value.starts_with('#')
+            && value[1..].chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)),
+        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+        "pid" => value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()),
+        _ => true,
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        let passports = Day04::parse(&input).unwrap();
+        let rendered: Vec<String> = passports.iter().map(|p| p.to_string()).collect();
+        let rendered = rendered.join("\n\n");
+        prop_assert_eq!(Day04::parse(&rendered).unwrap(), passports);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        let parsed = Day04::parse(&input).unwrap();
+
+        let has_required = |p: &Vec<(&str, &str)>| REQUIRED.iter().all(|key| p.iter().any(|(k, _)| k == key));
+        let part1 = passports(&input).iter().filter(|p| has_required(p)).count();
+        let part2 = passports(&input).iter()
+            .filter(|p| has_required(p) && p.iter().all(|(k, v)| is_valid(k, v)))
+            .count();
+        prop_assert_eq!(Day04::part1(&parsed).unwrap(), part1);
+        prop_assert_eq!(Day04::part2(&parsed).unwrap(), part2);
+    }
+}
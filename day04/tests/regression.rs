@@ -0,0 +1,18 @@
+use aoc_common::regression::parse_error;
+use day04::Day04;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day04>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day04>("ecl:gry pid:860033327\nbyr:1937 foo:1\n");
+    assert_eq!(d.message(), "expected 'byr', 'iyr', 'eyr', 'hgt', 'hcl', 'ecl', 'pid' or 'cid', found 'foo'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 10)));
+
+    let d = parse_error::<Day04>("byr\n");
+    assert_eq!(d.message(), "expected ':', found end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 4)));
+}
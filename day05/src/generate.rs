@@ -0,0 +1,27 @@
+use aoc_common::generate::{lines, Rng, SliceRandom, StdRng};
+
+/// A contiguous run of about `size` seat ids with one missing from the
+/// middle, in random order.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let len = size.clamp(3, 1000);
+    let first = rng.random_range(0..=1024 - len);
+    let missing = first + rng.random_range(1..len - 1);
+
+    let mut ids: Vec<usize> = (first..first + len).filter(|&id| id != missing).collect();
+    ids.shuffle(rng);
+    lines(ids.iter().map(|&id| seat(id)))
+}
+
+fn seat(id: usize) -> String {
+    (0..10).rev()
+        .map(|bit| {
+            let set = id >> bit & 1 == 1;
+            match (bit >= 3, set) {
+                (true, false) => 'F',
+                (true, true) => 'B',
+                (false, false) => 'L',
+                (false, true) => 'R',
+            }
+        })
+        .collect()
+}
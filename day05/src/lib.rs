@@ -0,0 +1,118 @@
+//! Day 5: Binary Boarding. Decode boarding passes into seats.
+
+use std::fmt;
+use std::str::FromStr;
+use anyhow::{anyhow, Result};
+use aoc_common::diagnostic::Diagnostic;
+use aoc_common::parse::parse_lines;
+use aoc_common::Solver;
+
+pub mod generate;
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input<'a> = Vec<Seat>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Seat>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Seat>) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Seat>) -> Result<usize> {
+        part2(input)
+    }
+}
+
+/// One boarding pass per line, like `FBFBBFFRLR`.
+pub fn parse(input: &str) -> Result<Vec<Seat>> {
+    parse_lines(input)
+}
+
+/// The highest seat ID.
+pub fn part1(input: &[Seat]) -> Result<usize> {
+    input.iter().map(|s| s.id()).max().ok_or_else(|| anyhow!("no seats"))
+}
+
+/// The ID of the one missing seat between two taken ones.
+pub fn part2(input: &[Seat]) -> Result<usize> {
+    let mut ids: Vec<usize> = input.iter().map(|s| s.id()).collect();
+    ids.sort();
+
+    ids.iter()
+        .zip(ids.iter().skip(1))
+        .find_map(|(&id, &next_id)| {
+            if id + 1 == next_id {
+                None
+            }
+            else {
+                Some(id + 1)
+            }
+        })
+        .ok_or_else(|| anyhow!("no missing id"))
+}
+
+/// A seat on the plane, from the `F`/`B` row and `L`/`R` column steps of
+/// a boarding pass.
+pub struct Seat {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Seat {
+    pub fn id(&self) -> usize {
+        self.row * 8 + self.col
+    }
+}
+
+impl FromStr for Seat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((i, c)) = s.char_indices().find(|&(_, c)| !"FBLR".contains(c)) {
+            let message = format!("invalid step '{}', expected one of 'F', 'B', 'L' or 'R'", c);
+            return Err(Diagnostic::new(&s[i..i + c.len_utf8()], message).into());
+        }
+        if s.len() != 10 {
+            return Err(Diagnostic::new(s, format!("expected 10 steps but got {}", s.len())).into());
+        }
+
+        let row = binary_partition(&s[0..7], 0, 128)?;
+        let col = binary_partition(&s[7..7+3], 0, 8)?;
+        Ok(Seat { row, col })
+    }
+}
+
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let row: String = (0..7).rev().map(|bit| if self.row >> bit & 1 == 1 { 'B' } else { 'F' }).collect();
+        let col: String = (0..3).rev().map(|bit| if self.col >> bit & 1 == 1 { 'R' } else { 'L' }).collect();
+        write!(f, "{}{}", row, col)
+    }
+}
+
+/// Narrows `low..high` down to one value, keeping the lower half for each
+/// `F` or `L` and the upper half for each `B` or `R`.
+pub fn binary_partition(steps: &str, mut low: usize, mut high: usize) -> Result<usize> {
+    for c in steps.chars() {
+        let mid = (low + high) / 2;
+        match c {
+            'F' | 'L' => high = mid,
+            'B' | 'R' => low = mid,
+            _   => return Err(anyhow!("invalid step '{}'", c)),
+        };
+    }
+
+    if low + 1 == high {
+        Ok(low)
+    }
+    else {
+        Err(anyhow!("steps do not converge to a single value {} {} {}", low, high, steps))
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day05::{generate, Day05};
+use proptest::prelude::*;
+
+fn id(seat: &str) -> usize {
+    let bits: String = seat.chars().map(|c| if c == 'B' || c == 'R' { '1' } else { '0' }).collect();
+    usize::from_str_radix(&bits, 2).unwrap()
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..200) {
+        let input = generate::input(&mut rng(seed), size);
+        let seats = Day05::parse(&input).unwrap();
+        let rendered: String = seats.iter().map(|s| format!("{}\n", s)).collect();
+        prop_assert_eq!(rendered, input);
+    }
+
+    #[test]
+    fn matches_reading_ids_as_binary(seed: u64, size in 0usize..200) {
+        let input = generate::input(&mut rng(seed), size);
+        let seats = Day05::parse(&input).unwrap();
+
+        let ids: Vec<usize> = input.lines().map(id).collect();
+        let max = *ids.iter().max().unwrap();
+        let min = *ids.iter().min().unwrap();
+        let missing = (min..max).find(|id| !ids.contains(id)).unwrap();
+        prop_assert_eq!(Day05::part1(&seats).unwrap(), max);
+        prop_assert_eq!(Day05::part2(&seats).unwrap(), missing);
+    }
+}
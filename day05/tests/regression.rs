@@ -0,0 +1,18 @@
+use aoc_common::regression::parse_error;
+use day05::Day05;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day05>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day05>("FBFBBFFRLR\nFBF\n");
+    assert_eq!(d.message(), "expected 10 steps but got 3");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 1)));
+
+    let d = parse_error::<Day05>("FBFBBFXRLR\n");
+    assert_eq!(d.message(), "invalid step 'X', expected one of 'F', 'B', 'L' or 'R'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 7)));
+}
@@ -0,0 +1,24 @@
+use aoc_common::generate::{Rng, SliceRandom, StdRng};
+
+/// `size` groups of people and the questions they answered yes to.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let groups: Vec<String> = (0..size)
+        .map(|_| {
+            let people = rng.random_range(1..=5);
+            (0..people).map(|_| person(rng) + "\n").collect()
+        })
+        .collect();
+    groups.join("\n")
+}
+
+fn person(rng: &mut StdRng) -> String {
+    let mut questions: Vec<char> = ('a'..='z').collect();
+    questions.shuffle(rng);
+    // Favour the start of the alphabet so that groups share answers.
+    let len = rng.random_range(1..=26);
+    let mut answers: Vec<char> = questions.into_iter().filter(|&q| q < 'h' || rng.random_bool(0.2)).take(len).collect();
+    if answers.is_empty() {
+        answers.push('a');
+    }
+    answers.into_iter().collect()
+}
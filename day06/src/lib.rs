@@ -0,0 +1,72 @@
+//! Day 6: Custom Customs. Count the questions groups answered "yes" to.
+
+use anyhow::{Result};
+use aoc_common::Solver;
+use std::collections::HashSet;
+
+pub mod generate;
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input<'a> = Vec<Vec<&'a str>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<&'_ str>>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Vec<&'_ str>>) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Vec<&'_ str>>) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
+/// Groups separated by blank lines, with one person's answers per line.
+pub fn parse(input: &str) -> Result<Vec<Vec<&str>>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+
+    for person in input.lines() {
+        if person.is_empty() {
+            groups.push(group);
+            group = vec![];
+        }
+        else {
+            group.push(person);
+        }
+    }
+    groups.push(group);
+
+    Ok(groups)
+}
+
+/// The sum over groups of the questions anyone answered.
+pub fn part1(input: &[Vec<&str>]) -> usize {
+    input.iter().map(|g| group_answers_any(g).len()).sum()
+}
+
+/// The sum over groups of the questions everyone answered.
+pub fn part2(input: &[Vec<&str>]) -> usize {
+    input.iter().map(|g| group_answers_all(g).len()).sum()
+}
+
+/// The questions anyone in `group` answered.
+pub fn group_answers_any(group: &[&str]) -> HashSet<char> {
+    group.iter().flat_map(|p| p.chars()).collect()
+}
+
+/// The questions everyone in `group` answered.
+pub fn group_answers_all(group: &[&str]) -> HashSet<char> {
+    let mut answers = group_answers_any(group);
+    for person in group {
+        let person_answers: HashSet<char> = person.chars().collect();
+        answers = answers.intersection(&person_answers).copied().collect();
+    } 
+    answers
+}
+
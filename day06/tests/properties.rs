@@ -0,0 +1,33 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day06::{generate, Day06};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        let groups = Day06::parse(&input).unwrap();
+        let rendered: Vec<String> = groups.iter()
+            .map(|g| g.iter().map(|p| format!("{}\n", p)).collect())
+            .collect();
+        prop_assert_eq!(rendered.join("\n"), input);
+    }
+
+    #[test]
+    fn matches_counting_each_question(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        let groups = Day06::parse(&input).unwrap();
+
+        let (mut any, mut all) = (0, 0);
+        for group in groups.iter() {
+            for q in 'a'..='z' {
+                let yes = group.iter().filter(|p| p.contains(q)).count();
+                any += (yes > 0) as usize;
+                all += (yes == group.len() && yes > 0) as usize;
+            }
+        }
+        prop_assert_eq!(Day06::part1(&groups).unwrap(), any);
+        prop_assert_eq!(Day06::part2(&groups).unwrap(), all);
+    }
+}
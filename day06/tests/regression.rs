@@ -0,0 +1,6 @@
+use day06::Day06;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day06>(env!("CARGO_MANIFEST_DIR"));
+}
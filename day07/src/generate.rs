@@ -0,0 +1,43 @@
+use aoc_common::generate::{IndexedRandom, Rng, SliceRandom, StdRng};
+use std::collections::HashSet;
+
+const ADJECTIVES: [&str; 12] = ["light", "dark", "bright", "muted", "shiny", "faded", "dotted", "vibrant",
+    "pale", "drab", "wavy", "plaid"];
+const COLORS: [&str; 12] = ["red", "orange", "white", "yellow", "gold", "olive", "plum", "blue",
+    "black", "teal", "lime", "tan"];
+
+/// Rules for `size` kinds of bag, including shiny gold. Bags only contain
+/// bags later in a random order, so there are no cycles, and the number of
+/// bags inside any one bag stays small enough to count.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut kinds = HashSet::new();
+    kinds.insert("shiny gold".to_string());
+    while kinds.len() < size.clamp(1, ADJECTIVES.len() * COLORS.len()) {
+        kinds.insert(format!("{} {}", ADJECTIVES.choose(rng).unwrap(), COLORS.choose(rng).unwrap()));
+    }
+    let mut kinds: Vec<String> = kinds.into_iter().collect();
+    kinds.sort();
+    kinds.shuffle(rng);
+
+    // How many bags each kind contains, filled in from the end.
+    let mut totals = vec![0u64; kinds.len()];
+    let mut rules = vec![String::new(); kinds.len()];
+    for i in (0..kinds.len()).rev() {
+        let mut children = vec![];
+        for j in i + 1..kinds.len() {
+            let count = rng.random_range(1..=4);
+            let total = totals[i] + count * (1 + totals[j]);
+            if children.len() < 4 && rng.random_bool(0.3) && total < 1_000_000 {
+                totals[i] = total;
+                let plural = if count == 1 { "" } else { "s" };
+                children.push(format!("{} {} bag{}", count, kinds[j], plural));
+            }
+        }
+
+        let children = if children.is_empty() { "no other bags".to_string() } else { children.join(", ") };
+        rules[i] = format!("{} bags contain {}.\n", kinds[i], children);
+    }
+
+    rules.shuffle(rng);
+    rules.concat()
+}
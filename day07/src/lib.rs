@@ -0,0 +1,119 @@
+//! Day 7: Handy Haversacks. Work out which bags hold which from the
+//! luggage rules.
+
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, HashSet};
+use aoc_common::combinator::{complete, map, number, opt, or, separated_list1, tag, take_until, PResult};
+use aoc_common::diagnostic::{Diagnostic, ResultExt};
+use aoc_common::parse::map_lines;
+use aoc_common::Solver;
+
+pub mod generate;
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input<'a> = (Rules<'a>, Rules<'a>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(Rules<'_>, Rules<'_>)> {
+        parse(input)
+    }
+
+    fn part1(input: &(Rules<'_>, Rules<'_>)) -> Result<usize> {
+        Ok(part1(&input.0))
+    }
+
+    fn part2(input: &(Rules<'_>, Rules<'_>)) -> Result<usize> {
+        part2(&input.1)
+    }
+}
+
+/// Bags of each kind mapped to the `(count, kind)` bags they are related
+/// to, either their children or their parents.
+pub type Rules<'a> = HashMap<&'a str, Vec<(usize, &'a str)>>;
+
+/// One rule per line, like `x bags contain 1 y bag, 2 z bags.`. Returns
+/// the rules from each bag to its parents and from each bag to its children.
+pub fn parse(input: &str) -> Result<(Rules<'_>, Rules<'_>)> {
+    let mut parent_rules: Rules = Default::default();
+
+    for line in input.lines() {
+        let (parent_kind, children) = parse_rule(line).at(line)?;
+        for (count, child_kind) in children {
+            parent_rules.entry(child_kind).or_insert(vec![])
+                .push((count, parent_kind));
+        }
+    }
+
+    let child_rules: Rules = map_lines(input, parse_rule)?.into_iter().collect();
+    for &(_, child_kind) in child_rules.values().flatten() {
+        if !child_rules.contains_key(child_kind) {
+            return Err(Diagnostic::new(child_kind, format!("no rule for '{}' bags", child_kind)).into());
+        }
+    }
+
+    Ok((parent_rules, child_rules))
+}
+
+/// How many kinds of bag can eventually hold a shiny gold bag.
+pub fn part1(parent_rules: &Rules) -> usize {
+    let kind = "shiny gold";
+    allowed_containers(kind, parent_rules).len()
+}
+
+/// Every kind of bag that can eventually hold a `kind` bag.
+pub fn allowed_containers<'a>(kind: &'a str, rules: &'a Rules) -> HashSet<&'a str> {
+    let empty = vec![];
+    let parents = rules.get(kind).unwrap_or(&empty);
+    let recursive_iter = parents.iter()
+        .flat_map(|(_, parent_kind)| allowed_containers(parent_kind, rules));
+    
+    parents.iter()
+        .map(|&(_, parent_kind)| parent_kind)
+        .chain(recursive_iter)
+        .collect()
+}
+
+/// How many bags a shiny gold bag holds.
+pub fn part2(child_rules: &Rules) -> Result<usize> {
+    let kind = "shiny gold";
+    if !child_rules.contains_key(kind) {
+        return Err(anyhow!("no rule for '{}' bags", kind));
+    }
+    Ok(child_count(kind, child_rules))
+}
+
+/// How many bags a `kind` bag holds. Panics if there is no rule for a
+/// bag it reaches.
+pub fn child_count<'a>(kind: &'a str, rules: &'a Rules) -> usize {
+    let children = rules.get(kind).unwrap();
+    children.iter()
+        .map(|(count, child_kind)| count * (1 + child_count(child_kind, rules)))
+        .sum()
+}
+
+/// Parses one rule into a bag and its `(count, kind)` children.
+pub fn parse_rule(s: &str) -> Result<(&str, Vec<(usize, &str)>)> {
+    complete(rule, s)
+}
+
+fn rule(s: &str) -> PResult<'_, (&str, Vec<(usize, &str)>)> {
+    let (kind, s) = take_until(" bags contain ")(s)?;
+    let (_, s) = tag(" bags contain ")(s)?;
+    let no_children = map(tag("no other bags"), |_| vec![]);
+    let (children, s) = or(no_children, separated_list1(child, tag(", ")))(s)?;
+    let (_, s) = tag(".")(s)?;
+    Ok(((kind, children), s))
+}
+
+/// A count and a bag, like `2 z bags`.
+fn child(s: &str) -> PResult<'_, (usize, &str)> {
+    let (count, s) = number(s)?;
+    let (_, s) = tag(" ")(s)?;
+    let (kind, s) = take_until(" bag")(s)?;
+    let (_, s) = tag(" bag")(s)?;
+    let (_, s) = opt(tag("s"))(s)?;
+    Ok(((count, kind), s))
+}
@@ -0,0 +1,58 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day07::{generate, Day07, Rules};
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+fn render(rules: &Rules) -> Vec<String> {
+    let mut lines: Vec<String> = rules.iter()
+        .map(|(kind, children)| {
+            let children: Vec<String> = children.iter()
+                .map(|(count, child)| format!("{} {} bag{}", count, child, if *count == 1 { "" } else { "s" }))
+                .collect();
+            let children = if children.is_empty() { "no other bags".to_string() } else { children.join(", ") };
+            format!("{} bags contain {}.", kind, children)
+        })
+        .collect();
+    lines.sort();
+    lines
+}
+
+/// Grows the set of bags that can hold shiny gold until it stops changing.
+fn containers(rules: &Rules) -> usize {
+    let mut found: HashSet<&str> = HashSet::new();
+    loop {
+        let before = found.len();
+        for (kind, children) in rules.iter() {
+            if children.iter().any(|(_, c)| *c == "shiny gold" || found.contains(c)) {
+                found.insert(kind);
+            }
+        }
+        if found.len() == before {
+            return found.len();
+        }
+    }
+}
+
+fn bags_inside(kind: &str, rules: &Rules) -> usize {
+    rules[kind].iter().map(|(count, child)| count * (1 + bags_inside(child, rules))).sum()
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 1usize..40) {
+        let input = generate::input(&mut rng(seed), size);
+        let (_, child_rules) = Day07::parse(&input).unwrap();
+        let mut lines: Vec<&str> = input.lines().collect();
+        lines.sort();
+        prop_assert_eq!(render(&child_rules), lines);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 1usize..40) {
+        let input = generate::input(&mut rng(seed), size);
+        let rules = Day07::parse(&input).unwrap();
+        prop_assert_eq!(Day07::part1(&rules).unwrap(), containers(&rules.1));
+        prop_assert_eq!(Day07::part2(&rules).unwrap(), bags_inside("shiny gold", &rules.1));
+    }
+}
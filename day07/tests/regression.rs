@@ -0,0 +1,22 @@
+use aoc_common::regression::parse_error;
+use day07::Day07;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day07>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day07>("light red bags contain no other bags\n");
+    assert_eq!(d.message(), "expected '.', found end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 37)));
+
+    let d = parse_error::<Day07>("light red bags contain two bright white bags.\n");
+    assert_eq!(d.message(), "expected 'no other bags' or a number, found 'two'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 24)));
+
+    let d = parse_error::<Day07>("light red bags contain 1 bright white bag, 2 muted yellow.\n");
+    assert_eq!(d.message(), "expected ' bag', found end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 59)));
+}
@@ -0,0 +1,52 @@
+use crate::{Instruction, VM};
+use aoc_common::generate::{lines, Rng, StdRng};
+
+/// A boot program of about `size` instructions that loops forever, but
+/// terminates if exactly one `jmp` or `nop` is swapped.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let len = size.max(2);
+    loop {
+        if let Some(program) = attempt(rng, len) {
+            return lines(program);
+        }
+    }
+}
+
+/// Breaks a program that only jumps forwards by turning one `nop` it runs
+/// into a backwards `jmp`, and keeps the result if that `nop` is the only fix.
+fn attempt(rng: &mut StdRng, len: usize) -> Option<Vec<Instruction>> {
+    let mut program: Vec<Instruction> = (0..len)
+        .map(|i| match rng.random_range(0..4) {
+            0 | 1 => Instruction::Acc { value: rng.random_range(-50..=50) },
+            2 => Instruction::Nop { value: rng.random_range(-(i as i64)..=0) },
+            _ => Instruction::Jmp { offset: rng.random_range(1..=3.min(len - i) as i64) },
+        })
+        .collect();
+
+    let candidates: Vec<usize> = (1..len)
+        .filter(|&i| matches!(program[i], Instruction::Nop { value } if value < 0))
+        .collect();
+    let &broken = candidates.get(rng.random_range(0..candidates.len().max(1)))?;
+    if let Instruction::Nop { value } = program[broken] {
+        program[broken] = Instruction::Jmp { offset: value };
+    }
+
+    let fixes = (0..len)
+        .filter(|&i| {
+            let mut fixed = program.clone();
+            fixed[i] = match fixed[i] {
+                Instruction::Jmp { offset } => Instruction::Nop { value: offset },
+                Instruction::Nop { value } => Instruction::Jmp { offset: value },
+                Instruction::Acc { .. } => return false,
+            };
+            VM::default().run(&fixed)
+        })
+        .count();
+
+    if !VM::default().run(&program) && fixes == 1 {
+        Some(program)
+    }
+    else {
+        None
+    }
+}
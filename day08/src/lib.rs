@@ -0,0 +1,139 @@
+//! Day 8: Handheld Halting. Run a boot program and fix its infinite loop.
+
+use anyhow::{anyhow, Result};
+use aoc_common::diagnostic::{Diagnostic, ResultExt};
+use aoc_common::parse::parse_lines;
+use aoc_common::Solver;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<i64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Result<i64> {
+        part2(input)
+    }
+}
+
+/// One instruction per line, like `acc +1`.
+pub fn parse(s: &str) -> Result<Vec<Instruction>> {
+    parse_lines(s)
+}
+
+/// The accumulator just before any instruction runs a second time.
+pub fn part1(input: &[Instruction]) -> i64 {
+    let mut vm: VM = Default::default();
+    vm.run(input);
+    vm.accumulator
+}
+
+/// The accumulator once the program terminates, after swapping the one
+/// `jmp` or `nop` that makes it do so.
+pub fn part2(input: &[Instruction]) -> Result<i64> {
+    use Instruction::*;
+    for i in 0..input.len() {
+        let mut program = input.to_vec();
+        let new_instruction = match program[i] {
+            Acc { .. } => continue,
+            Jmp { offset } => Nop { value: offset },
+            Nop { value } => Jmp { offset: value },
+        };
+        program[i] = new_instruction;
+
+        let mut vm: VM = Default::default();
+        if vm.run(&program) {
+            return Ok(vm.accumulator);
+        }
+    }
+
+    Err(anyhow!("no single instruction change makes the program terminate"))
+}
+
+/// The handheld's processor.
+#[derive(Default)]
+pub struct VM {
+    pub pc: i64,
+    pub accumulator: i64,
+}
+
+impl VM {
+    /// Runs `program` until it either terminates by running past its last
+    /// instruction, which returns true, or is about to repeat an instruction.
+    pub fn run(&mut self, program: &[Instruction]) -> bool {
+        let mut seen: Vec<bool> = program.iter().map(|_| false).collect();
+
+        while (0..(program.len() as i64)).contains(&self.pc) && !seen[self.pc as usize] {
+            seen[self.pc as usize] = true;
+            self.execute(&program[self.pc as usize]);
+        }
+
+        self.pc == (program.len() as i64)
+    }
+
+    pub fn execute(&mut self, instruction: &Instruction) {
+        use Instruction::*;
+        match instruction {
+            Acc { value } => {
+                self.accumulator += value;
+                self.pc += 1
+            },
+            Jmp { offset } => self.pc += offset,
+            Nop { .. } => self.pc += 1,
+        }
+    }
+
+}
+
+#[derive(Clone)]
+pub enum Instruction {
+    /// Adds `value` to the accumulator.
+    Acc { value: i64 },
+    /// Jumps `offset` instructions relative to itself.
+    Jmp { offset: i64 },
+    /// Does nothing; `value` only matters if it is swapped for a `Jmp`.
+    Nop { value: i64 },
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        use Instruction::*;
+
+        let mut parts = s.split_whitespace();
+        let opcode = parts.next().ok_or_else(|| Diagnostic::new(s, "missing opcode"))?;
+        let arg = parts.next().ok_or_else(|| Diagnostic::new(&s[s.len()..], "missing argument"))?;
+        let arg = arg.parse().at(arg)?;
+
+        match opcode {
+            "acc" => Ok( Acc { value: arg } ),
+            "jmp" => Ok( Jmp { offset: arg } ),
+            "nop" => Ok( Nop { value: arg } ),
+            _ => Err(Diagnostic::new(opcode, format!("invalid opcode '{}'", opcode)).into()),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Acc { value } => write!(f, "acc {:+}", value),
+            Instruction::Jmp { offset } => write!(f, "jmp {:+}", offset),
+            Instruction::Nop { value } => write!(f, "nop {:+}", value),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day08::{generate, Day08};
+use proptest::prelude::*;
+
+/// Runs `program` for a bounded number of steps instead of tracking visited
+/// instructions: one that runs longer than its length must be looping.
+fn run(program: &[(&str, i64)]) -> Option<i64> {
+    let (mut pc, mut acc) = (0i64, 0);
+    for _ in 0..=program.len() {
+        if pc == program.len() as i64 {
+            return Some(acc);
+        }
+        let (op, arg) = program[pc as usize];
+        match op {
+            "acc" => acc += arg,
+            "jmp" => pc += arg - 1,
+            _ => (),
+        }
+        pc += 1;
+    }
+    None
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let program = Day08::parse(&input).unwrap();
+        let rendered: String = program.iter().map(|i| format!("{}\n", i)).collect();
+        prop_assert_eq!(rendered, input);
+    }
+
+    #[test]
+    fn part2_matches_bounded_runs(seed: u64, size in 0usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let program: Vec<(&str, i64)> = input.lines()
+            .map(|l| (&l[..3], l[4..].parse().unwrap()))
+            .collect();
+
+        let fixed: Vec<i64> = (0..program.len())
+            .filter_map(|i| {
+                let mut fixed = program.clone();
+                fixed[i].0 = match fixed[i].0 { "jmp" => "nop", "nop" => "jmp", _ => return None };
+                run(&fixed)
+            })
+            .collect();
+        prop_assert_eq!(run(&program), None);
+        prop_assert_eq!(fixed.len(), 1);
+        prop_assert_eq!(Day08::part2(&Day08::parse(&input).unwrap()).unwrap(), fixed[0]);
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::regression::parse_error;
+use day08::Day08;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day08>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day08>("nop +0\nfoo +1\n");
+    assert_eq!(d.message(), "invalid opcode 'foo'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 1)));
+}
@@ -0,0 +1,51 @@
+use aoc_common::generate::{lines, IndexedRandom, Rng, SliceRandom, StdRng};
+
+const PREAMBLE: usize = 25;
+
+/// About `size` numbers after a preamble of 25, where every number is the
+/// sum of two of the 25 before it except one, which is instead the sum of
+/// a contiguous run of earlier numbers.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut numbers: Vec<i64> = (1..=100).collect();
+    numbers.shuffle(rng);
+    numbers.truncate(PREAMBLE);
+
+    let invalid_at = PREAMBLE + rng.random_range(2..=size.max(2));
+    let len = size.max(invalid_at - PREAMBLE + 1) + PREAMBLE;
+    let mut placed = false;
+    while !placed || numbers.len() < len {
+        if !placed && numbers.len() >= invalid_at {
+            if let Some(invalid) = contiguous_sum(rng, &numbers) {
+                numbers.push(invalid);
+                placed = true;
+                continue;
+            }
+        }
+        numbers.push(pair_sum(rng, &numbers));
+    }
+
+    lines(numbers)
+}
+
+fn window(numbers: &[i64]) -> &[i64] {
+    &numbers[numbers.len() - PREAMBLE..]
+}
+
+fn pair_sum(rng: &mut StdRng, numbers: &[i64]) -> i64 {
+    loop {
+        let pair: Vec<&i64> = window(numbers).choose_multiple(rng, 2).collect();
+        if pair[0] != pair[1] {
+            return pair[0] + pair[1];
+        }
+    }
+}
+
+fn contiguous_sum(rng: &mut StdRng, numbers: &[i64]) -> Option<i64> {
+    let len = rng.random_range(3..=10);
+    let start = rng.random_range(0..=numbers.len() - len);
+    let sum = numbers[start..start + len].iter().sum();
+
+    let window = window(numbers);
+    let is_pair_sum = window.iter().any(|&x| x * 2 != sum && window.contains(&(sum - x)));
+    if is_pair_sum { None } else { Some(sum) }
+}
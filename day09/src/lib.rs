@@ -0,0 +1,84 @@
+//! Day 9: Encoding Error. Find the weakness in the XMAS cipher.
+
+use anyhow::{anyhow, Result};
+use aoc_common::Solver;
+use aoc_common::multiset::RefCountSet;
+use aoc_common::parse::parse_lines;
+
+pub mod generate;
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> Result<i64> {
+        part2(input, part1(input)?)
+    }
+}
+
+/// One number per line.
+pub fn parse(s: &str) -> Result<Vec<i64>> {
+    parse_lines(s)
+}
+
+/// The first number after the 25-number preamble that is not the sum of
+/// two different numbers among the 25 before it.
+pub fn part1(input: &[i64]) -> Result<i64> {
+    let preamble_len = 25;
+
+    let mut iter = input.iter().enumerate().map(|(i, &v)| (i, v));
+    let mut prev: RefCountSet<i64> = iter.by_ref().take(preamble_len).map(|(_, v)| v).collect();
+
+    for (i, v) in iter {
+        // is valid
+        let mut valid = false;
+        for (&prev_v, _) in prev.iter() {
+            let diff = v - prev_v;
+            if diff != prev_v && prev.contains(&diff) {
+                valid = true;
+            }
+        }
+
+        if !valid {
+            return Ok(v);
+        }
+        
+        let remove = input[i-preamble_len];
+        prev.remove(&remove);
+        prev.add(v);
+    }
+
+    Err(anyhow!("did not find valid part1 answer"))
+}
+
+/// The sum of the smallest and largest numbers in a contiguous run of at
+/// least two that sums to `part1_answer`.
+pub fn part2(input: &[i64], part1_answer: i64) -> Result<i64> {
+    for start_index in 0..input.len() {
+        for end_index in (start_index+2)..input.len() {
+            let sum: i64 = input[start_index..end_index].iter().sum();
+            if sum > part1_answer {
+                break;
+            }
+
+            if sum == part1_answer {
+                let min = input[start_index..end_index].iter().min().unwrap();
+                let max = input[start_index..end_index].iter().max().unwrap();
+                return Ok(min + max);
+            }
+        }
+    }
+
+    Err(anyhow!("no contiguous range sums to {}", part1_answer))
+}
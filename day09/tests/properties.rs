@@ -0,0 +1,38 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day09::{generate, Day09};
+use proptest::prelude::*;
+
+fn first_invalid(numbers: &[i64]) -> i64 {
+    for i in 25..numbers.len() {
+        let window = &numbers[i - 25..i];
+        let valid = window.iter().enumerate()
+            .any(|(j, x)| window[j + 1..].iter().any(|y| x != y && x + y == numbers[i]));
+        if !valid {
+            return numbers[i];
+        }
+    }
+    panic!("every number is valid");
+}
+
+fn weakness(numbers: &[i64], target: i64) -> i64 {
+    for start in 0..numbers.len() {
+        for end in start + 2..numbers.len() {
+            let run = &numbers[start..end];
+            if run.iter().sum::<i64>() == target {
+                return run.iter().min().unwrap() + run.iter().max().unwrap();
+            }
+        }
+    }
+    panic!("no run sums to {}", target);
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..60) {
+        let numbers = Day09::parse(&generate::input(&mut rng(seed), size)).unwrap();
+        let invalid = first_invalid(&numbers);
+        prop_assert_eq!(Day09::part1(&numbers).unwrap(), invalid);
+        prop_assert_eq!(Day09::part2(&numbers).unwrap(), weakness(&numbers, invalid));
+    }
+}
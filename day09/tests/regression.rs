@@ -0,0 +1,6 @@
+use day09::Day09;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day09>(env!("CARGO_MANIFEST_DIR"));
+}
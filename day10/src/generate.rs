@@ -0,0 +1,14 @@
+use aoc_common::generate::{lines, Rng, SliceRandom, StdRng};
+
+/// `size` adapters whose sorted joltages differ by 1 or 3, in random order.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut joltage = 0;
+    let mut adapters: Vec<i64> = (0..size.max(1))
+        .map(|_| {
+            joltage += if rng.random_bool(0.7) { 1 } else { 3 };
+            joltage
+        })
+        .collect();
+    adapters.shuffle(rng);
+    lines(adapters)
+}
@@ -0,0 +1,97 @@
+//! Day 10: Adapter Array. Chain joltage adapters from the outlet to the
+//! device.
+
+use anyhow::{anyhow, Result};
+use aoc_common::Solver;
+use aoc_common::parse::parse_lines;
+use std::collections::HashMap;
+
+pub mod generate;
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
+/// One adapter's joltage per line.
+pub fn parse(s: &str) -> Result<Vec<i64>> {
+    parse_lines(s)
+}
+
+/// The number of 1-jolt differences times the number of 3-jolt
+/// differences when using every adapter.
+pub fn part1(input: &[i64]) -> Result<usize> {
+    let joltages = sorted_joltages(input);
+
+    let mut diff1_count = 0;
+    let mut diff3_count = 0;
+    for i in 0..(joltages.len()-1) {
+        let diff = joltages[i+1] - joltages[i];
+        match diff {
+            1 => diff1_count += 1,
+            3 => diff3_count += 1,
+            _ => return Err(anyhow!("invalid difference: {}", diff)),
+        }
+    }
+
+    Ok(diff1_count * diff3_count)
+}
+
+/// How many ways the adapters can connect the outlet to the device.
+pub fn part2(input: &[i64]) -> usize {
+    let joltages = sorted_joltages(input);
+
+    let mut cache = HashMap::new();
+    count_ways(&joltages, &mut cache)
+}
+
+/// How many ways `adapters[0]` can reach the last adapter, given the
+/// adapters in sorted order. `cache` memoizes by starting joltage.
+pub fn count_ways(adapters: &[i64], cache: &mut HashMap<i64, usize>) -> usize {
+    if adapters.len() == 1 {
+        return 1;
+    }
+
+    let current = adapters[0];
+    if let Some(&count) = cache.get(&current) {
+        return count;
+    }
+
+    let mut count = 0;
+    for i in 1..adapters.len() {
+        let next_adapter = adapters[i];
+        if next_adapter > current + 3 {
+            break;
+        }
+
+        count += count_ways(&adapters[i..], cache);
+    }
+
+    cache.insert(current, count);
+    count
+}
+
+/// The adapters in order, with the outlet (0) at the start and the device
+/// (3 more than the largest adapter) at the end.
+pub fn sorted_joltages(input: &[i64]) -> Vec<i64> {
+    let mut joltages = vec![0];
+    joltages.extend(input);
+    joltages.sort();
+    joltages.push(joltages[..].last().unwrap() + 3);
+    joltages
+}
@@ -0,0 +1,38 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day10::{generate, Day10};
+use proptest::prelude::*;
+
+/// Tries every subset of adapters.
+fn arrangements(adapters: &[i64]) -> usize {
+    let device = adapters.iter().max().unwrap() + 3;
+    (0..1u32 << adapters.len())
+        .filter(|subset| {
+            let mut chain: Vec<i64> = (0..adapters.len())
+                .filter(|i| subset >> i & 1 == 1)
+                .map(|i| adapters[i])
+                .collect();
+            chain.sort();
+            chain.insert(0, 0);
+            chain.push(device);
+            chain.windows(2).all(|w| w[1] - w[0] <= 3)
+        })
+        .count()
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..14) {
+        let adapters = Day10::parse(&generate::input(&mut rng(seed), size)).unwrap();
+
+        let mut chain = adapters.clone();
+        chain.push(0);
+        chain.sort();
+        let diffs: Vec<i64> = chain.windows(2).map(|w| w[1] - w[0]).collect();
+        let ones = diffs.iter().filter(|&&d| d == 1).count();
+        let threes = diffs.iter().filter(|&&d| d == 3).count() + 1;
+
+        prop_assert_eq!(Day10::part1(&adapters).unwrap(), ones * threes);
+        prop_assert_eq!(Day10::part2(&adapters).unwrap(), arrangements(&adapters));
+    }
+}
@@ -0,0 +1,6 @@
+use day10::Day10;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day10>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,7 @@
+use aoc_common::generate::{grid, Rng, StdRng};
+
+/// A seat layout with `size` rows of mostly empty seats and some floor.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let cols = rng.random_range(1..=size.max(1));
+    grid(rng, size.max(1), cols, "LLLL.")
+}
@@ -0,0 +1,228 @@
+//! Day 11: Seating System. Simulate people choosing seats until nobody
+//! moves.
+
+use anyhow::{anyhow, Result};
+use aoc_common::Solver;
+use aoc_common::grid::{Coord, FromChar, Grid, ToChar};
+use aoc_common::render::{Cell, Frame, Render, Rgb};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input<'a> = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Map> {
+        parse(input)
+    }
+
+    fn part1(input: &Map) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Map) -> Result<usize> {
+        part2(input)
+    }
+}
+
+impl Render for Day11 {
+    /// The layout after each round until it settles.
+    fn frames(input: &Map, part: u8) -> Vec<Frame> {
+        let mut map = input.clone();
+        let mut frames = vec![map.draw()];
+        let mut seen = HashSet::new();
+        loop {
+            let changed = if part == 1 { map.step() } else { map.step_part2() };
+            if !changed || !seen.insert(map.to_string()) { break; }
+            frames.push(map.draw());
+        }
+        frames
+    }
+}
+
+/// A seat layout of `.` floor, `L` empty seats and `#` occupied seats.
+pub fn parse(s: &str) -> Result<Map> {
+    s.parse()
+}
+
+/// The occupied seats once the layout settles, looking at adjacent seats.
+pub fn part1(input: &Map) -> Result<usize> {
+    settle(input, Map::step)
+}
+
+/// The occupied seats once the layout settles, looking at the first seat
+/// visible in each direction.
+pub fn part2(input: &Map) -> Result<usize> {
+    settle(input, Map::step_part2)
+}
+
+/// The occupied seats once `step` stops changing the layout. Some layouts
+/// flip between states forever instead, which is an error.
+fn settle(input: &Map, step: fn(&mut Map) -> bool) -> Result<usize> {
+    let mut map = input.clone();
+    let mut seen = HashSet::new();
+    while step(&mut map) {
+        if !seen.insert(map.to_string()) {
+            return Err(anyhow!("the layout repeats itself after {} rounds and never settles", seen.len()));
+        }
+    }
+    Ok(map.iter().filter(|&(_, &e)| e == MapElement::Occupied).count())
+}
+
+
+/// A seat layout, double buffered so that each step can read the last one
+/// while writing the next.
+#[derive(Clone)]
+pub struct Map {
+    maps: [Grid<MapElement>; 2],
+    current_map: usize,
+}
+
+impl Map {
+    /// Runs one round of the part 1 rules, returning whether any seat
+    /// changed.
+    pub fn step(&mut self) -> bool {
+        use MapElement::*;
+
+        let mut any_change: bool = false;
+        let new_idx = (self.current_map + 1) % 2;
+        for coord in self.current().coords() {
+            let map = self.current();
+            let current_element = map[coord];
+            let new_element = match current_element {
+                Floor => Floor,
+                Empty => if map.adjacent(&coord).map(|c| map[c]).any(|e| e == Occupied) { Empty } else { Occupied },
+                Occupied => if map.adjacent(&coord).map(|c| map[c]).filter(|&e| e == Occupied).count() >= 4 { Empty } else { Occupied },
+            };
+
+            if new_element != current_element {
+                any_change = true;
+            }
+
+            self.maps[new_idx][coord] = new_element;
+        }
+        
+        self.current_map = new_idx;
+        any_change
+    }
+
+    /// Runs one round of the part 2 rules, returning whether any seat
+    /// changed.
+    pub fn step_part2(&mut self) -> bool {
+        use MapElement::*;
+
+        let mut any_change: bool = false;
+        let new_idx = (self.current_map + 1) % 2;
+        for coord in self.current().coords() {
+            let current_element = self.current()[coord];
+            let new_element = match current_element {
+                Floor => Floor,
+                Empty => if self.part2_adjacent_seats(&coord).any(|&e| e == Occupied) { Empty } else { Occupied },
+                Occupied => if self.part2_adjacent_seats(&coord).filter(|&&e| e == Occupied).count() >= 5 { Empty } else { Occupied },
+            };
+
+            if new_element != current_element {
+                any_change = true;
+            }
+
+            self.maps[new_idx][coord] = new_element;
+        }
+        
+        self.current_map = new_idx;
+        any_change
+    }
+
+    fn part2_adjacent_seats<'a>(&'a self, coord: &'a Coord) -> impl Iterator<Item = &'a MapElement> {
+        (-1..=1).flat_map(move |dr| (-1..=1).map(move |dc| (dr, dc)))
+            .filter(|&(r, c)| !(r == 0 && c == 0))
+            .filter_map(move |(r, c)| self.seat_in_direction(coord, &(r, c)))
+    }
+
+    fn seat_in_direction(&self, coord: &Coord, delta: &Coord) -> Option<&MapElement> {
+        let mut pos = (coord.0 + delta.0, coord.1 + delta.1);
+        while let Some(e) = self.current().get(&pos) {
+            if *e != MapElement::Floor {
+                return Some(e);
+            }
+
+            pos = (pos.0 + delta.0, pos.1 + delta.1);
+        }
+
+        None
+    }
+
+    /// The current layout, with empty seats green and occupied ones red.
+    pub fn draw(&self) -> Frame {
+        self.current().map(|e| match e {
+            MapElement::Floor => Cell::new('.', Rgb(60, 60, 60)),
+            MapElement::Empty => Cell::new('L', Rgb(40, 180, 60)),
+            MapElement::Occupied => Cell::new('#', Rgb(220, 50, 50)),
+        })
+    }
+
+    /// The layout after the latest step.
+    pub fn current(&self) -> &Grid<MapElement> {
+        &self.maps[self.current_map]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &MapElement)> {
+        self.current().iter()
+    }
+}
+
+
+impl FromStr for Map {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let map0: Grid<MapElement> = s.parse()?;
+        let map1 = map0.clone();
+        let maps = [map0, map1];
+        let current_map = 0;
+
+        Ok(Self { maps, current_map })
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.current())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MapElement {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl FromChar for MapElement {
+    type Err = anyhow::Error;
+
+    fn from_char(c: &char) -> Result<Self> {
+        use MapElement::*;
+        match c {
+            '.' => Ok(Floor),
+            'L' => Ok(Empty),
+            '#' => Ok(Occupied),
+            _   => Err(anyhow!("unknown character '{}'", c)),
+        }
+    }
+}
+
+impl ToChar for MapElement {
+    fn to_char(&self) -> char {
+        match self {
+            MapElement::Floor => '.',
+            MapElement::Empty => 'L',
+            MapElement::Occupied => '#',
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day11::{generate, Day11};
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+/// Runs the seating rules on a plain char grid until nothing changes, or
+/// `None` if a layout comes round again.
+fn settle(input: &str, tolerance: usize, line_of_sight: bool) -> Option<usize> {
+    let mut seen = HashSet::new();
+    let mut seats: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let (rows, cols) = (seats.len() as i64, seats[0].len() as i64);
+    let at = |seats: &Vec<Vec<char>>, r: i64, c: i64| {
+        if r < 0 || r >= rows || c < 0 || c >= cols { None } else { Some(seats[r as usize][c as usize]) }
+    };
+
+    loop {
+        let mut next = seats.clone();
+        for r in 0..rows {
+            for c in 0..cols {
+                let mut occupied = 0;
+                for (dr, dc) in [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)] {
+                    let (mut rr, mut cc) = (r + dr, c + dc);
+                    while line_of_sight && at(&seats, rr, cc) == Some('.') {
+                        rr += dr;
+                        cc += dc;
+                    }
+                    occupied += (at(&seats, rr, cc) == Some('#')) as usize;
+                }
+                let seat = &mut next[r as usize][c as usize];
+                if *seat == 'L' && occupied == 0 {
+                    *seat = '#';
+                }
+                else if *seat == '#' && occupied >= tolerance {
+                    *seat = 'L';
+                }
+            }
+        }
+        if next == seats {
+            return Some(seats.iter().flatten().filter(|&&s| s == '#').count());
+        }
+        if !seen.insert(next.clone()) {
+            return None;
+        }
+        seats = next;
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 1usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        prop_assert_eq!(Day11::parse(&input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 1usize..12) {
+        let input = generate::input(&mut rng(seed), size);
+        let map = Day11::parse(&input).unwrap();
+        prop_assert_eq!(Day11::part1(&map).ok(), settle(&input, 4, false));
+        prop_assert_eq!(Day11::part2(&map).ok(), settle(&input, 5, true));
+    }
+}
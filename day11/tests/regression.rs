@@ -0,0 +1,52 @@
+use aoc_common::regression::parse_error;
+use aoc_common::render::render;
+use aoc_common::Solver;
+use day11::Day11;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day11>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day11>("L.\nLx\n");
+    assert_eq!(d.message(), "unknown character 'x'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 2)));
+}
+
+#[test]
+fn never_settles() {
+    let layout = "\
+LLLLLLLLLLL
+LLLL..LLLLL
+LL.L.LLLLL.
+LLLLLL.L.LL
+LLLLLLLL.LL
+LLLLL..L.LL
+LL.LLLL.LL.
+LL.LLLL.LLL
+LLLLLL..L.L
+L.LLLLLLLL.
+L..LL.LL.LL
+";
+    let map = Day11::parse(layout).unwrap();
+    assert!(Day11::part1(&map).is_err());
+    assert!(Day11::part2(&map).is_ok());
+}
+
+#[test]
+fn frames() {
+    let example = include_str!("../input_test.txt");
+
+    // The starting layout, then one per round until nothing changes.
+    let frames = render::<Day11>(example, 1).unwrap();
+    assert_eq!(frames.len(), 6);
+    assert_eq!(frames[0][(0, 0)].glyph, 'L');
+    assert_eq!(frames[1][(0, 0)].glyph, '#');
+    assert_eq!(frames[5].iter().filter(|(_, c)| c.glyph == '#').count(), 37);
+
+    let frames = render::<Day11>(example, 2).unwrap();
+    assert_eq!(frames.len(), 7);
+    assert_eq!(frames[6].iter().filter(|(_, c)| c.glyph == '#').count(), 26);
+}
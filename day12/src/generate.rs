@@ -0,0 +1,14 @@
+use aoc_common::generate::{lines, IndexedRandom, Rng, StdRng};
+
+/// `size` navigation instructions. Turns are multiples of 90 degrees, and
+/// every value is positive so that each instruction has one spelling.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let action = *['N', 'S', 'E', 'W', 'L', 'R', 'F'].choose(rng).unwrap();
+        let value = match action {
+            'L' | 'R' => *[90, 180, 270].choose(rng).unwrap(),
+            _ => rng.random_range(1..=100),
+        };
+        format!("{}{}", action, value)
+    }))
+}
@@ -0,0 +1,154 @@
+//! Day 12: Rain Risk. Follow the ferry's navigation instructions.
+
+use anyhow::Result;
+use aoc_common::diagnostic::{Diagnostic, ResultExt};
+use aoc_common::Solver;
+use aoc_common::parse::parse_lines;
+use aoc_common::vec2::Vec2;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<i64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Result<i64> {
+        Ok(part2(input))
+    }
+}
+
+/// One instruction per line, like `F10` or `R90`.
+pub fn parse(s: &str) -> Result<Vec<Instruction>> {
+    parse_lines(s)
+}
+
+/// The ship's Manhattan distance from the start, moving the ship itself.
+pub fn part1(input: &[Instruction]) -> i64 {
+    let mut ship: Ship = Default::default();
+    for instruction in input {
+        ship.step(instruction);
+    }
+    ship.position.manhattan_magnitude()
+}
+
+/// The ship's Manhattan distance from the start, moving a waypoint.
+pub fn part2(input: &[Instruction]) -> i64 {
+    let mut ship: ShipPart2 = Default::default();
+    for instruction in input {
+        ship.step(instruction);
+    }
+    ship.position.manhattan_magnitude()
+}
+
+/// A ship that instructions move and turn directly, starting east.
+pub struct Ship {
+    pub position: Vec2<i64>,
+    pub orientation: Vec2<i64>,
+}
+
+impl Ship {
+    pub fn step(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::MoveDirection(v) => self.position += *v,
+            Instruction::Turn(v) => self.orientation.turn(*v),
+            Instruction::MoveForward(v) => self.position += self.orientation * *v,
+        }
+    }
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Ship {
+            position: Vec2::new(0, 0),
+            orientation: Vec2::new(1, 0), // East
+        }
+    }
+}
+
+/// A ship that instructions steer through a waypoint relative to it,
+/// starting 10 east and 1 north.
+pub struct ShipPart2 {
+    pub position: Vec2<i64>,
+    pub waypoint: Vec2<i64>,
+}
+
+impl ShipPart2 {
+    pub fn step(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::MoveDirection(v) => self.waypoint += *v,
+            Instruction::Turn(v) => self.waypoint.turn(*v),
+            Instruction::MoveForward(v) => self.position += self.waypoint * *v,
+        }
+    }
+}
+
+impl Default for ShipPart2 {
+    fn default() -> Self {
+        ShipPart2 {
+            position: Vec2::new(0, 0),
+            waypoint: Vec2::new(10, 1),
+        }
+    }
+}
+
+pub enum Instruction {
+    /// `N`, `S`, `E` or `W`, as a vector.
+    MoveDirection(Vec2<i64>),
+    /// `L` or `R`, in quarter turns counterclockwise.
+    Turn(i64),
+    /// `F`.
+    MoveForward(i64),
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        use Instruction::*;
+        let action = s.chars().next().ok_or_else(|| Diagnostic::new(s, "missing action character"))?;
+        let (action_str, value_str) = s.split_at(action.len_utf8());
+        let value: i64 = value_str.parse().at(value_str)?;
+
+        Ok(match action {
+            'N' => MoveDirection(Vec2::new(0, value)),
+            'S' => MoveDirection(Vec2::new(0, -value)),
+            'E' => MoveDirection(Vec2::new(value, 0)),
+            'W' => MoveDirection(Vec2::new(-value, 0)),
+
+            'L' | 'R' if value % 90 != 0 => return Err(Diagnostic::new(value_str, format!("turn not a multiple of 90: {}", value)).into()),
+            'L' => Turn(value / 90),
+            'R' => Turn(-value / 90),
+
+            'F' => MoveForward(value),
+
+            c => return Err(Diagnostic::new(action_str, format!("invalid action '{}'", c)).into()),
+        })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::MoveDirection(v) if v.x > 0 => write!(f, "E{}", v.x),
+            Instruction::MoveDirection(v) if v.x < 0 => write!(f, "W{}", -v.x),
+            Instruction::MoveDirection(v) if v.y < 0 => write!(f, "S{}", -v.y),
+            Instruction::MoveDirection(v) => write!(f, "N{}", v.y),
+            Instruction::Turn(steps) if *steps < 0 => write!(f, "R{}", -steps * 90),
+            Instruction::Turn(steps) => write!(f, "L{}", steps * 90),
+            Instruction::MoveForward(v) => write!(f, "F{}", v),
+        }
+    }
+}
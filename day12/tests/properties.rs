@@ -0,0 +1,47 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day12::{generate, Day12};
+use proptest::prelude::*;
+
+/// Rotates `(x, y)` anticlockwise by `degrees` one quarter turn at a time.
+fn rotate((x, y): (i64, i64), degrees: i64) -> (i64, i64) {
+    (0..degrees.rem_euclid(360) / 90).fold((x, y), |(x, y), _| (-y, x))
+}
+
+fn navigate(input: &str, waypoint: bool) -> i64 {
+    let mut ship = (0, 0);
+    let mut heading = if waypoint { (10, 1) } else { (1, 0) };
+    for line in input.lines() {
+        let value: i64 = line[1..].parse().unwrap();
+        let moved = |(x, y): (i64, i64), (dx, dy): (i64, i64)| (x + dx * value, y + dy * value);
+        let target = if waypoint { &mut heading } else { &mut ship };
+        match &line[..1] {
+            "N" => *target = moved(*target, (0, 1)),
+            "S" => *target = moved(*target, (0, -1)),
+            "E" => *target = moved(*target, (1, 0)),
+            "W" => *target = moved(*target, (-1, 0)),
+            "L" => heading = rotate(heading, value),
+            "R" => heading = rotate(heading, -value),
+            _ => ship = moved(ship, heading),
+        }
+    }
+    ship.0.abs() + ship.1.abs()
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let instructions = Day12::parse(&input).unwrap();
+        let rendered: String = instructions.iter().map(|i| format!("{}\n", i)).collect();
+        prop_assert_eq!(rendered, input);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let instructions = Day12::parse(&input).unwrap();
+        prop_assert_eq!(Day12::part1(&instructions).unwrap(), navigate(&input, false));
+        prop_assert_eq!(Day12::part2(&instructions).unwrap(), navigate(&input, true));
+    }
+}
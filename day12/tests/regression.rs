@@ -0,0 +1,14 @@
+use aoc_common::regression::parse_error;
+use day12::Day12;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day12>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day12>("F10\nQ3\n");
+    assert_eq!(d.message(), "invalid action 'Q'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 1)));
+}
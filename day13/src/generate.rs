@@ -0,0 +1,29 @@
+use aoc_common::generate::{IndexedRandom, Rng, SliceRandom, StdRng};
+
+const PRIMES: [usize; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// A timestamp and a schedule of `size` slots, starting and ending with a
+/// bus. Bus IDs are distinct primes, as in the puzzle, so a time for part 2
+/// always exists.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let slots = size.max(1);
+    let buses = rng.random_range(1..=slots.min(6));
+
+    let mut positions: Vec<usize> = (1..slots - 1).collect();
+    positions.shuffle(rng);
+    positions.truncate(buses.saturating_sub(2));
+    positions.push(0);
+    if slots > 1 {
+        positions.push(slots - 1);
+    }
+
+    let ids: Vec<&usize> = PRIMES.choose_multiple(rng, positions.len()).collect();
+    let schedule: Vec<String> = (0..slots)
+        .map(|i| match positions.iter().position(|&p| p == i) {
+            Some(bus) => ids[bus].to_string(),
+            None => "x".to_string(),
+        })
+        .collect();
+
+    format!("{}\n{}\n", rng.random_range(1..1_000_000), schedule.join(","))
+}
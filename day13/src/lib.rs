@@ -0,0 +1,84 @@
+//! Day 13: Shuttle Search. Work out when the shuttle buses line up.
+
+use anyhow::{anyhow, Result};
+use aoc_common::diagnostic::{Diagnostic, ResultExt};
+use aoc_common::modular;
+use aoc_common::Solver;
+
+pub mod generate;
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input<'a> = (usize, Vec<(usize, usize)>);
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<(usize, Vec<(usize, usize)>)> {
+        parse(input)
+    }
+
+    fn part1(input: &(usize, Vec<(usize, usize)>)) -> Result<usize> {
+        part1(input.0, &input.1)
+    }
+
+    fn part2(input: &(usize, Vec<(usize, usize)>)) -> Result<i64> {
+        part2(&input.1)
+    }
+}
+
+/// The earliest departure time, and the `(offset, id)` of each bus that is
+/// in service.
+pub fn parse(s: &str) -> Result<(usize, Vec<(usize, usize)>)> {
+    let mut lines = s.lines();
+    let timestamp_str = lines.next().ok_or_else(|| Diagnostic::new(s, "missing timestamp line"))?;
+    let timestamp = timestamp_str.parse().at(timestamp_str)?;
+    let buses_str = lines.next().ok_or_else(|| Diagnostic::new(&s[s.len()..], "missing bus IDs"))?;
+    let buses = buses_str.split(',')
+        .enumerate()
+        .filter(|&(_, s)| s != "x")
+        .map(|(i, s)| match s.parse() {
+            Ok(0) => Err(Diagnostic::new(s, "bus ID cannot be 0").into()),
+            Ok(v) => Ok((i, v)),
+            Err(e) => Err(e).at(s),
+        })
+        .collect::<Result<_, _>>()?;
+    
+    Ok((timestamp, buses))
+
+}
+
+/// The ID of the first bus to leave after `timestamp` times the wait for it.
+pub fn part1(timestamp: usize, buses: &[(usize, usize)]) -> Result<usize> {
+    let (id, wait_time) = buses.iter()
+        .map(|(_, id)| (id, (id - timestamp % id) % id))
+        .min_by_key(|&(_, t)| t)
+        .ok_or_else(|| anyhow!("no buses"))?;
+    
+    Ok(id * wait_time)
+}
+
+/// The earliest time when each bus leaves its offset after that time.
+pub fn part2(buses: &[(usize, usize)]) -> Result<i64> {
+    let ps: Vec<i64> = buses.iter().map(|&(_, b)| b as i64).collect();
+    let xs: Vec<i64> = buses.iter().map(|&(i, b)| ((b as i64) - (i as i64)) % (b as i64) ).collect();
+
+    chinese_remainder_theorem(&ps, &xs)
+}
+
+/// The smallest non-negative `x` with `x % ps[i] == xs[i]` for every `i`.
+/// The `ps` must be pairwise coprime, or else this is an error.
+// https://math.stackexchange.com/a/2060259
+pub fn chinese_remainder_theorem(ps: &[i64], xs: &[i64]) -> Result<i64> {
+    let big_p = ps.iter().try_fold(1i64, |product, &p| product.checked_mul(p))
+        .ok_or_else(|| anyhow!("the product of {:?} overflows", ps))?;
+    let mut big_x: i128 = 0;
+    for (&p, &x) in ps.iter().zip(xs.iter()) {
+        let rest = big_p / p;
+        let inverse = modular::inverse(rest.rem_euclid(p) as u64, p as u64)
+            .ok_or_else(|| anyhow!("{} is not coprime with the other moduli", p))?;
+        big_x = (big_x + x as i128 * rest as i128 * inverse as i128) % big_p as i128;
+    }
+
+    Ok(big_x.rem_euclid(big_p as i128) as i64)
+}
@@ -0,0 +1,35 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day13::{generate, Day13};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 1usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        let (timestamp, buses) = Day13::parse(&input).unwrap();
+
+        let mut schedule = vec!["x".to_string(); buses.last().unwrap().0 + 1];
+        for &(i, id) in buses.iter() {
+            schedule[i] = id.to_string();
+        }
+        prop_assert_eq!(format!("{}\n{}\n", timestamp, schedule.join(",")), input);
+    }
+
+    #[test]
+    fn matches_brute_force(seed: u64, size in 1usize..10) {
+        let input = generate::input(&mut rng(seed), size);
+        let notes = Day13::parse(&input).unwrap();
+        let (timestamp, buses) = &notes;
+
+        let depart = (*timestamp..).find(|t| buses.iter().any(|(_, id)| t % id == 0)).unwrap();
+        let bus = buses.iter().find(|(_, id)| depart % id == 0).unwrap().1;
+        prop_assert_eq!(Day13::part1(&notes).unwrap(), bus * (depart - timestamp));
+
+        let first = buses[0].1;
+        let contest = (0..).step_by(first)
+            .find(|t| buses.iter().all(|(i, id)| (t + i) % id == 0))
+            .unwrap();
+        prop_assert_eq!(Day13::part2(&notes).unwrap(), contest as i64);
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_common::regression::parse_error;
+use aoc_common::Solver;
+use day13::Day13;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day13>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day13>("939\n7,y\n");
+    assert_eq!(d.message(), "invalid digit found in string");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 3)));
+}
+
+#[test]
+fn moduli_not_coprime() {
+    let notes = Day13::parse("939\n3,x,5,x,23,x,x,5\n").unwrap();
+    assert!(Day13::part2(&notes).is_err());
+
+    let notes = Day13::parse("939\n4,x,6\n").unwrap();
+    assert!(Day13::part2(&notes).is_err());
+}
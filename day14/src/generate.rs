@@ -0,0 +1,22 @@
+use aoc_common::generate::{Rng, StdRng};
+
+/// A mask followed by about `size` writes, with a new mask now and then.
+/// Masks have at most 6 floating bits to keep part 2's address count small.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut s = mask(rng);
+    for _ in 0..size {
+        if rng.random_bool(0.2) {
+            s += &mask(rng);
+        }
+        s += &format!("mem[{}] = {}\n", rng.random_range(0..1 << 16), rng.random_range(0..1u64 << 36));
+    }
+    s
+}
+
+fn mask(rng: &mut StdRng) -> String {
+    let mut bits: Vec<char> = (0..36).map(|_| if rng.random_bool(0.5) { '1' } else { '0' }).collect();
+    for _ in 0..rng.random_range(0..=6) {
+        bits[rng.random_range(0..36)] = 'X';
+    }
+    format!("mask = {}\n", bits.into_iter().collect::<String>())
+}
@@ -0,0 +1,219 @@
+//! Day 14: Docking Data. Run the sea port's bitmask initialization program.
+
+use anyhow::{anyhow, Result};
+use aoc_common::combinator::{complete, number, or, tag, take_while1, Error, PResult};
+use aoc_common::Solver;
+use aoc_common::parse::parse_lines;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Result<u64> {
+        part2(input)
+    }
+}
+
+/// One instruction per line, like `mask = <36 bits>` or `mem[8] = 11`.
+pub fn parse(s: &str) -> Result<Vec<Instruction>> {
+    parse_lines(s)
+}
+
+/// The sum of memory after masking values.
+pub fn part1(input: &[Instruction]) -> Result<u64> {
+    let mut vm: VM = Default::default();
+    vm.run(input)?;
+    Ok(vm.mem.values().sum())
+}
+
+/// The sum of memory after masking addresses, with floating bits.
+pub fn part2(input: &[Instruction]) -> Result<u64> {
+    let mut vm: VMPart2 = Default::default();
+    vm.run(input)?;
+    Ok(vm.mem.values().sum())
+}
+
+/// The version 1 decoder chip, which masks values.
+#[derive(Default)]
+pub struct VM {
+    pub mask: Option<Bitmask>,
+    pub mem: HashMap<usize, u64>,
+}
+
+impl VM {
+    pub fn run(&mut self, program: &[Instruction]) -> Result<()> {
+        for instruction in program.iter() {
+            self.execute(instruction)?;
+        }
+        Ok(())
+    }
+
+    pub fn execute(&mut self, instruction: &Instruction) -> Result<()> {
+        match *instruction {
+            Instruction::Mask { mask } => self.mask = Some(mask),
+            Instruction::Mem { addr, value } => {
+                let mask = self.mask.ok_or_else(|| anyhow!("got a mem instruction before a mask instruction"))?;
+                let calc_val = (value & !mask.mask) | (mask.value & mask.mask);
+                self.mem.insert(addr, calc_val);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The version 2 decoder chip, which masks addresses. `mask` holds every
+/// combination of the floating bits.
+#[derive(Default)]
+pub struct VMPart2 {
+    pub mask: Option<Vec<Bitmask>>,
+    pub mem: HashMap<usize, u64>,
+}
+
+impl VMPart2 {
+    pub fn run(&mut self, program: &[Instruction]) -> Result<()> {
+        for instruction in program.iter() {
+            self.execute(instruction)?;
+        }
+        Ok(())
+    }
+
+    pub fn execute(&mut self, instruction: &Instruction) -> Result<()> {
+        match *instruction {
+            Instruction::Mask { mask } => self.mask = Some(all_masks(&mask)),
+            Instruction::Mem { addr, value } => {
+                let masks = self.mask.as_ref().ok_or_else(|| anyhow!("got a mem instruction before a mask instruction"))?;
+                for mask in masks {
+                    let calc_addr = (addr & !(mask.mask as usize)) | ((mask.value & mask.mask) as usize);
+                    self.mem.insert(calc_addr, value);
+
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every mask the floating (unmasked) bits of `mask` can stand for, which
+/// overwrite those bits and leave its `0` bits alone.
+pub fn all_masks(mask: &Bitmask) -> Vec<Bitmask> {
+    let mut masks = vec![];
+    all_masks_helper(*mask, 0, &mut masks);
+    masks
+}
+
+fn all_masks_helper(mut mask: Bitmask, start_bit: u64, masks: &mut Vec<Bitmask>) {
+    let mut any_floating = false;
+    for bit in start_bit..36 {
+        if (mask.mask >> bit) & 1 == 0 {
+            any_floating = true;
+            let new_mask = mask.mask | (1 << bit);
+            for bit_value in 0..=1 {
+                let new_value = mask.value | (bit_value << bit);
+                all_masks_helper(Bitmask { mask: new_mask, value: new_value }, bit + 1, masks);
+            }
+            break
+        }
+        else if (mask.value >> bit) & 1 == 0 {
+            mask.mask &= !(1 << bit);
+        }
+    }
+
+    if !any_floating {
+        masks.push(mask);
+    }
+}
+
+/// The bits set in `mask` are overwritten with the same bits of `value`;
+/// the others are `X`.
+#[derive(Clone, Copy)]
+pub struct Bitmask {
+    pub mask: u64,
+    pub value: u64,
+}
+
+pub enum Instruction {
+    Mask { mask: Bitmask },
+    Mem { addr: usize, value: u64 },
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        complete(or(Instruction::mask, Instruction::mem), s)
+    }
+}
+
+impl Instruction {
+    /// `mask = <36 bits>`, most significant first.
+    fn mask(s: &str) -> PResult<'_, Self> {
+        let (_, s) = tag("mask = ")(s)?;
+        let (bits, rest) = take_while1("a mask of '0', '1' and 'X'", |c| matches!(c, '0' | '1' | 'X'))(s)?;
+        if bits.len() != 36 {
+            return Err(Error::new(s, "a mask of 36 bits"));
+        }
+
+        let mut value = 0;
+        let mut mask = 0;
+        for c in bits.chars() {
+            value <<= 1;
+            mask <<= 1;
+            match c {
+                '0' => {
+                    value |= 0;
+                    mask |= 1;
+                },
+                '1' => {
+                    value |= 1;
+                    mask |= 1;
+                },
+                _ => (),
+            }
+        }
+
+        Ok((Instruction::Mask { mask: Bitmask { mask, value } }, rest))
+    }
+
+    /// `mem[<addr>] = <value>`.
+    fn mem(s: &str) -> PResult<'_, Self> {
+        let (_, s) = tag("mem[")(s)?;
+        let (addr, s) = number(s)?;
+        let (_, s) = tag("] = ")(s)?;
+        let (value, s) = number(s)?;
+        Ok((Instruction::Mem { addr, value }, s))
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Mask { mask } => {
+                let bits: String = (0..36).rev()
+                    .map(|bit| match (mask.mask >> bit & 1, mask.value >> bit & 1) {
+                        (0, _) => 'X',
+                        (_, 0) => '0',
+                        _ => '1',
+                    })
+                    .collect();
+                write!(f, "mask = {}", bits)
+            },
+            Instruction::Mem { addr, value } => write!(f, "mem[{}] = {}", addr, value),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day14::{generate, Day14};
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+fn bits(n: u64) -> Vec<char> {
+    format!("{:036b}", n).chars().collect()
+}
+
+fn number(bits: &[char]) -> u64 {
+    u64::from_str_radix(&bits.iter().collect::<String>(), 2).unwrap()
+}
+
+/// Every address `mask` maps `addr` to, expanding one floating bit at a time.
+fn addresses(mask: &[char], addr: u64) -> Vec<u64> {
+    let mut results = vec![bits(addr)];
+    for (i, &m) in mask.iter().enumerate() {
+        results = results.into_iter()
+            .flat_map(|a| {
+                let options = match m { '0' => vec![a[i]], '1' => vec!['1'], _ => vec!['0', '1'] };
+                options.into_iter().map(move |c| {
+                    let mut a = a.clone();
+                    a[i] = c;
+                    a
+                })
+            })
+            .collect();
+    }
+    results.iter().map(|a| number(a)).collect()
+}
+
+fn run(input: &str, decoder_v2: bool) -> u64 {
+    let mut mask = vec![];
+    let mut mem = HashMap::new();
+    for line in input.lines() {
+        let (lhs, rhs) = line.split_once(" = ").unwrap();
+        if lhs == "mask" {
+            mask = rhs.chars().collect();
+            continue;
+        }
+
+        let addr: u64 = lhs[4..lhs.len() - 1].parse().unwrap();
+        let value: u64 = rhs.parse().unwrap();
+        if decoder_v2 {
+            for a in addresses(&mask, addr) {
+                mem.insert(a, value);
+            }
+        }
+        else {
+            let masked: Vec<char> = mask.iter().zip(bits(value)).map(|(&m, v)| if m == 'X' { v } else { m }).collect();
+            mem.insert(addr, number(&masked));
+        }
+    }
+    mem.values().sum()
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..50) {
+        let input = generate::input(&mut rng(seed), size);
+        let program = Day14::parse(&input).unwrap();
+        let rendered: String = program.iter().map(|i| format!("{}\n", i)).collect();
+        prop_assert_eq!(rendered, input);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        let program = Day14::parse(&input).unwrap();
+        prop_assert_eq!(Day14::part1(&program).unwrap(), run(&input, false));
+        prop_assert_eq!(Day14::part2(&program).unwrap(), run(&input, true));
+    }
+}
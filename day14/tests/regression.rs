@@ -0,0 +1,26 @@
+use aoc_common::regression::parse_error;
+use day14::Day14;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day14>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day14>("mask = 0000000000000000000000000000000X100Z\n");
+    assert_eq!(d.message(), "expected a mask of 36 bits, found '0000000000000000000000000000000X100Z'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 8)));
+
+    let d = parse_error::<Day14>("mas = 1\n");
+    assert_eq!(d.message(), "expected 'mask = ' or 'mem[', found 'mas'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 1)));
+
+    let d = parse_error::<Day14>("mem[8 = 11\n");
+    assert_eq!(d.message(), "expected '] = ', found ' '");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 6)));
+
+    let d = parse_error::<Day14>("mem[8] = 1x\n");
+    assert_eq!(d.message(), "expected end of line, found 'x'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 11)));
+}
@@ -0,0 +1,9 @@
+use aoc_common::generate::{SliceRandom, StdRng};
+
+/// `size` distinct starting numbers.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut numbers: Vec<usize> = (0..size.max(1) * 3).collect();
+    numbers.shuffle(rng);
+    let numbers: Vec<String> = numbers.iter().take(size.max(1)).map(|n| n.to_string()).collect();
+    numbers.join(",") + "\n"
+}
@@ -0,0 +1,79 @@
+//! Day 15: Rambunctious Recitation. Play the elves' memory game.
+
+use anyhow::Result;
+use aoc_common::diagnostic::ResultExt;
+use aoc_common::Solver;
+use std::collections::HashMap;
+
+pub mod generate;
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> Result<i64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<i64>) -> Result<i64> {
+        Ok(part2(input))
+    }
+}
+
+/// The comma-separated starting numbers.
+pub fn parse(s: &str) -> Result<Vec<i64>> {
+    s.trim().split(',').map(|n| n.parse().at(n)).collect()
+}
+
+/// The 2020th number spoken.
+pub fn part1(input: &[i64]) -> i64 {
+    let mut game = MemoryGame::new(input);
+    game.run(2020)
+}
+
+/// The 30000000th number spoken.
+pub fn part2(input: &[i64]) -> i64 {
+    let mut game = MemoryGame::new(input);
+    game.run(30000000)
+}
+
+/// The elves' memory game, remembering the last turn each number was
+/// spoken on.
+pub struct MemoryGame {
+    spoken: HashMap<i64, i64>,
+    last: i64,
+    turn: i64,
+}
+
+impl MemoryGame {
+    /// A game that has just spoken `starting_nums`, which must not be
+    /// empty.
+    pub fn new(starting_nums: &[i64]) -> Self {
+        let turn = starting_nums.len() as i64;
+        let (first_nums, last_num) = starting_nums.split_at(starting_nums.len() - 1);
+        let spoken = first_nums.iter().copied().zip(1..).collect();
+        let last = last_num[0];
+        Self { spoken, last, turn }
+    }
+
+    /// Plays until turn `to_turn` and returns the number spoken on it.
+    pub fn run(&mut self, to_turn: i64) -> i64 {
+        while self.turn < to_turn {
+            self.run_turn();
+        }
+        self.last
+    }
+
+    pub fn run_turn(&mut self) {
+        let last_spoken_at = self.spoken.insert(self.last, self.turn).unwrap_or(self.turn);
+        self.last = self.turn - last_spoken_at;
+        self.turn += 1;
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day15::{generate, Day15};
+use proptest::prelude::*;
+
+/// Searches the whole history for the previous time each number was spoken.
+fn nth_spoken(start: &[i64], n: usize) -> i64 {
+    let mut spoken = start.to_vec();
+    while spoken.len() < n {
+        let (&last, before) = spoken.split_last().unwrap();
+        let next = match before.iter().rposition(|&x| x == last) {
+            Some(i) => (before.len() - i) as i64,
+            None => 0,
+        };
+        spoken.push(next);
+    }
+    spoken[n - 1]
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        let numbers = Day15::parse(&input).unwrap();
+        let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        prop_assert_eq!(numbers.join(",") + "\n", input);
+    }
+
+    #[test]
+    fn part1_matches_reference(seed: u64, size in 0usize..20) {
+        let numbers = Day15::parse(&generate::input(&mut rng(seed), size)).unwrap();
+        prop_assert_eq!(Day15::part1(&numbers).unwrap(), nth_spoken(&numbers, 2020));
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::regression::parse_error;
+use day15::Day15;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day15>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day15>("0,a\n");
+    assert_eq!(d.message(), "invalid digit found in string");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 3)));
+}
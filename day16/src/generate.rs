@@ -0,0 +1,58 @@
+use aoc_common::generate::{IndexedRandom, Rng, SliceRandom, StdRng};
+
+const NAMES: [&str; 12] = ["departure location", "departure station", "departure platform",
+    "departure track", "arrival location", "class", "duration", "price", "route", "row", "seat", "zone"];
+
+/// Notes with up to 6 fields and about `size` nearby tickets, some of them
+/// invalid.
+///
+/// Rule `k` accepts `1-(10k+4) or (10k+6)-(10k+9)`, so each rule accepts
+/// everything the rules before it do. Ticket columns are in a random order,
+/// and every column has a value that only its rule and later ones accept,
+/// which makes the mapping from rules to columns unique.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let fields = rng.random_range(1..=6);
+    let names: Vec<&str> = NAMES.choose_multiple(rng, fields).copied().collect();
+    let mut order: Vec<usize> = (0..fields).collect();
+    order.shuffle(rng);
+
+    let value = |rng: &mut StdRng, k: usize, top: bool| {
+        let top_values = [10 * k + 6, 10 * k + 7, 10 * k + 8, 10 * k + 9];
+        if top || rng.random_bool(0.3) {
+            *top_values.choose(rng).unwrap()
+        }
+        else {
+            rng.random_range(1..=10 * k + 4)
+        }
+    };
+    let ticket = |rng: &mut StdRng, top: bool| -> Vec<usize> {
+        order.iter().map(|&k| value(rng, k, top)).collect()
+    };
+
+    let mut nearby = vec![ticket(rng, true)];
+    for _ in 1..size.max(1) {
+        let mut t = ticket(rng, false);
+        if rng.random_bool(0.3) {
+            let i = rng.random_range(0..t.len());
+            t[i] = *[0, rng.random_range(10 * fields..1000)].choose(rng).unwrap();
+        }
+        nearby.push(t);
+    }
+    nearby.shuffle(rng);
+
+    let mut s = String::new();
+    for (k, name) in names.iter().enumerate() {
+        s += &format!("{}: 1-{} or {}-{}\n", name, 10 * k + 4, 10 * k + 6, 10 * k + 9);
+    }
+    s += &format!("\nyour ticket:\n{}\n", join(&ticket(rng, false)));
+    s += "\nnearby tickets:\n";
+    for t in nearby {
+        s += &(join(&t) + "\n");
+    }
+    s
+}
+
+fn join(ticket: &[usize]) -> String {
+    let values: Vec<String> = ticket.iter().map(|v| v.to_string()).collect();
+    values.join(",")
+}
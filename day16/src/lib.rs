@@ -0,0 +1,209 @@
+//! Day 16: Ticket Translation. Work out which ticket field is which from
+//! the rules and the nearby tickets.
+
+use anyhow::{anyhow, Result};
+use aoc_common::assign::assign;
+use aoc_common::combinator::{complete, number, separated_list1, tag, take_while1, PResult};
+use aoc_common::diagnostic::{Diagnostic, ResultExt};
+use aoc_common::Solver;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input<'a> = Notes;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Notes> {
+        parse(input)
+    }
+
+    fn part1(input: &Notes) -> Result<i64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Notes) -> Result<i64> {
+        part2(input)
+    }
+}
+
+/// The rules, your ticket and the nearby tickets, in sections separated
+/// by blank lines.
+pub fn parse(s: &str) -> Result<Notes> {
+    s.parse()
+}
+
+/// The sum of the values on nearby tickets that no rule accepts.
+pub fn part1(notes: &Notes) -> i64 {
+    notes.nearby.iter()
+        .flat_map(|ticket| {
+            ticket.iter().filter(|value| !notes.rules.iter().any(|rule| rule.is_valid(value)))
+        })
+        .sum()
+}
+
+/// The product of the fields on your ticket whose names start with
+/// `departure`, after matching rules to fields using the valid tickets.
+pub fn part2(notes: &Notes) -> Result<i64> {
+    let valid_tickets: Vec<&Ticket> = notes.nearby.iter()
+        .filter(|ticket| {
+            ticket.iter().all(|value| notes.rules.iter().any(|rule| rule.is_valid(value)))
+        })
+        .collect();
+    
+    let field_count = notes.rules.len();
+
+    // valid[rule_idx] = the fields the rule is valid for on every ticket
+    let valid: HashMap<usize, HashSet<usize>> = notes.rules.iter()
+        .enumerate()
+        .map(|(rule_idx, rule)| {
+            let fields = (0..field_count)
+                .filter(|&field_idx| valid_tickets.iter().all(|ticket| rule.is_valid(&ticket[field_idx])))
+                .collect();
+            (rule_idx, fields)
+        })
+        .collect();
+
+    // map rule_idx -> field_idx
+    let mapping = assign(&valid)
+        .ok_or_else(|| anyhow!("no assignment of rules to fields is valid for every ticket"))?;
+
+    // sanity check
+    for ticket in valid_tickets.iter() {
+        for (rule_idx, rule) in notes.rules.iter().enumerate() {
+            let field_idx = *mapping.get(&rule_idx).unwrap();
+            assert!(rule.is_valid(&ticket[field_idx]));
+        }
+    }
+
+    notes.rules.iter()
+        .enumerate()
+        .filter(|(_, r)| r.name.starts_with("departure"))
+        .map(|(i, r)| {
+            let value = notes.ticket[mapping[&i]];
+            if !r.is_valid(&value) {
+                return Err(anyhow!("your ticket's {} is {}, which its rule does not accept", r.name, value));
+            }
+            Ok(value)
+        })
+        .product()
+}
+
+/// Your notes on the ticket fields.
+pub struct Notes {
+    pub rules: Vec<Rule>,
+    pub ticket: Ticket,
+    pub nearby: Vec<Ticket>,
+}
+
+impl FromStr for Notes {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+        let eof = &s[s.len()..];
+
+        let rules: Vec<Rule> = lines.by_ref().take_while(|&l| !l.is_empty())
+            .map(|l| l.parse().at(l))
+            .collect::<Result<_>>()?;
+        
+        expect_line("your ticket:", &mut lines, eof)?; 
+
+        let ticket = lines.next().ok_or_else(|| Diagnostic::new(eof, "expected ticket but got EOF"))
+            .map_err(anyhow::Error::from)
+            .and_then(|l| parse_ticket(l, rules.len()))?;
+        
+        expect_line("", &mut lines, eof)?;
+        expect_line("nearby tickets:", &mut lines, eof)?;
+        
+        let nearby = lines.map(|l| parse_ticket(l, rules.len())).collect::<Result<_>>()?;
+
+        Ok(Self { rules, ticket, nearby })
+    }
+}
+
+impl fmt::Display for Notes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for rule in self.rules.iter() {
+            writeln!(f, "{}", rule)?;
+        }
+        writeln!(f, "\nyour ticket:\n{}\n\nnearby tickets:", join_ticket(&self.ticket))?;
+        for ticket in self.nearby.iter() {
+            writeln!(f, "{}", join_ticket(ticket))?;
+        }
+        Ok(())
+    }
+}
+
+/// A ticket's field values, in the order they appear on it.
+pub type Ticket = Vec<i64>;
+
+/// A ticket line, which must have one field per rule.
+fn parse_ticket(s: &str, field_count: usize) -> Result<Ticket> {
+    let ticket: Ticket = complete(separated_list1(number, tag(",")), s)?;
+    if ticket.len() != field_count {
+        return Err(Diagnostic::new(s, format!("expected {} fields, one per rule, but got {}", field_count, ticket.len())).into());
+    }
+    Ok(ticket)
+}
+
+fn join_ticket(ticket: &Ticket) -> String {
+    let values: Vec<String> = ticket.iter().map(|v| v.to_string()).collect();
+    values.join(",")
+}
+
+fn expect_line(e: &str, lines: &mut std::str::Lines, eof: &str) -> Result<()> {
+    match lines.next() {
+        Some(l) if l == e => Ok(()),
+        Some(l) => Err(Diagnostic::new(l, format!("expected '{}' but got '{}'", e, l)).into()),
+        None => Err(Diagnostic::new(eof, format!("expected '{}' but got EOF", e)).into()),
+    }
+}
+
+/// A field name and the inclusive ranges of values it accepts.
+pub struct Rule {
+    pub name: String,
+    pub ranges: Vec<(i64, i64)>,
+}
+
+impl Rule {
+    pub fn is_valid(&self, value: &i64) -> bool {
+        self.ranges.iter().any(|&(low, high)| (low..=high).contains(value))
+    }
+}
+
+impl FromStr for Rule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        complete(rule, s)
+    }
+}
+
+/// `name: 1-3 or 5-7`.
+fn rule(s: &str) -> PResult<'_, Rule> {
+    let (name, s) = take_while1("a field name", |c| c != ':')(s)?;
+    let (_, s) = tag(": ")(s)?;
+    let (ranges, s) = separated_list1(range, tag(" or "))(s)?;
+    Ok((Rule { name: name.to_owned(), ranges }, s))
+}
+
+fn range(s: &str) -> PResult<'_, (i64, i64)> {
+    let (low, s) = number(s)?;
+    let (_, s) = tag("-")(s)?;
+    let (high, s) = number(s)?;
+    Ok(((low, high), s))
+}
+
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(|(low, high)| format!("{}-{}", low, high)).collect();
+        write!(f, "{}: {}", self.name, ranges.join(" or "))
+    }
+}
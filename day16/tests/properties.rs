@@ -0,0 +1,76 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day16::{generate, Day16};
+use proptest::prelude::*;
+
+struct Rule {
+    departure: bool,
+    ranges: Vec<(i64, i64)>,
+}
+
+impl Rule {
+    fn accepts(&self, v: i64) -> bool {
+        self.ranges.iter().any(|&(low, high)| low <= v && v <= high)
+    }
+}
+
+fn parse(input: &str) -> (Vec<Rule>, Vec<i64>, Vec<Vec<i64>>) {
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    let rules = sections[0].lines()
+        .map(|l| {
+            let (name, ranges) = l.split_once(": ").unwrap();
+            let ranges = ranges.split(" or ")
+                .map(|r| {
+                    let (low, high) = r.split_once('-').unwrap();
+                    (low.parse().unwrap(), high.parse().unwrap())
+                })
+                .collect();
+            Rule { departure: name.starts_with("departure"), ranges }
+        })
+        .collect();
+    let ticket = |l: &str| l.split(',').map(|v| v.parse().unwrap()).collect::<Vec<i64>>();
+    let mine = ticket(sections[1].lines().nth(1).unwrap());
+    let nearby = sections[2].lines().skip(1).map(ticket).collect();
+    (rules, mine, nearby)
+}
+
+/// Tries every assignment of rules to columns.
+fn assignments(columns: &mut Vec<usize>, rules: &[Rule], tickets: &[&Vec<i64>], found: &mut Vec<Vec<usize>>) {
+    let rule = columns.len();
+    if rule == rules.len() {
+        found.push(columns.clone());
+        return;
+    }
+    for column in 0..rules.len() {
+        if !columns.contains(&column) && tickets.iter().all(|t| rules[rule].accepts(t[column])) {
+            columns.push(column);
+            assignments(columns, rules, tickets, found);
+            columns.pop();
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        prop_assert_eq!(Day16::parse(&input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn matches_brute_force(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        let notes = Day16::parse(&input).unwrap();
+        let (rules, mine, nearby) = parse(&input);
+
+        let error_rate: i64 = nearby.iter().flatten().filter(|&&v| !rules.iter().any(|r| r.accepts(v))).sum();
+        prop_assert_eq!(Day16::part1(&notes).unwrap(), error_rate);
+
+        let valid: Vec<&Vec<i64>> = nearby.iter().filter(|t| t.iter().all(|&v| rules.iter().any(|r| r.accepts(v)))).collect();
+        let mut found = vec![];
+        assignments(&mut vec![], &rules, &valid, &mut found);
+        prop_assert_eq!(found.len(), 1);
+        let product: i64 = rules.iter().zip(found[0].iter()).filter(|(r, _)| r.departure).map(|(_, &c)| mine[c]).product();
+        prop_assert_eq!(Day16::part2(&notes).unwrap(), product);
+    }
+}
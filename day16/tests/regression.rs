@@ -0,0 +1,40 @@
+use aoc_common::regression::parse_error;
+use aoc_common::Solver;
+use day16::Day16;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day16>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn ticket_lengths() {
+    let notes = "class: 0-1 or 4-19\nrow: 0-5 or 8-19\n\nyour ticket:\n11,12\n\nnearby tickets:\n3,9\n15,1,5\n";
+    let e = Day16::parse(notes).err().unwrap();
+    assert_eq!(e.to_string(), "expected 2 fields, one per rule, but got 3");
+
+    let notes = "class: 0-1 or 4-19\nrow: 0-5 or 8-19\n\nyour ticket:\n11\n\nnearby tickets:\n3,9\n";
+    assert!(Day16::parse(notes).is_err());
+}
+
+#[test]
+fn invalid_departure_value() {
+    let notes = "departure time: 0-1 or 4-19\nrow: 0-5 or 8-19\n\nyour ticket:\n11,2\n\nnearby tickets:\n3,9\n15,1\n";
+    let notes = Day16::parse(notes).unwrap();
+    assert!(Day16::part2(&notes).is_err());
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day16>("class 0-1\n\nyour ticket:\n1\n\nnearby tickets:\n1\n");
+    assert_eq!(d.message(), "expected ': ', found end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 10)));
+
+    let d = parse_error::<Day16>("class: 0-1 or 4\n\nyour ticket:\n1\n\nnearby tickets:\n1\n");
+    assert_eq!(d.message(), "expected '-', found end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 16)));
+
+    let d = parse_error::<Day16>("class: 0-1\n\nyour ticket:\n1,\n\nnearby tickets:\n1\n");
+    assert_eq!(d.message(), "expected a number, found end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((4, 3)));
+}
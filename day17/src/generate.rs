@@ -0,0 +1,6 @@
+use aoc_common::generate::{grid, StdRng};
+
+/// A `size` by `size` slice of active and inactive cubes.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    grid(rng, size.max(1), size.max(1), ".#")
+}
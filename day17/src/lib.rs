@@ -0,0 +1,226 @@
+//! Day 17: Conway Cubes. Run a Game of Life in three and four dimensions.
+
+use anyhow::Result;
+use aoc_common::diagnostic::Diagnostic;
+use aoc_common::life;
+use aoc_common::render::{self, Cell, Frame, Render, Rgb};
+use aoc_common::Solver;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+pub mod generate;
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input<'a> = Vec<(i64, i64)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(i64, i64)>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<(i64, i64)>) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<(i64, i64)>) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
+impl Render for Day17 {
+    /// The pocket dimension before and after each cycle.
+    fn frames(input: &Vec<(i64, i64)>, part: u8) -> Vec<Frame> {
+        if part == 1 { frames::<Point3>(input) } else { frames::<Point4>(input) }
+    }
+}
+
+fn frames<T: Point>(input: &[(i64, i64)]) -> Vec<Frame> {
+    let mut dimension: PocketDimension<T> = input.iter().copied().collect();
+    let mut frames = vec![dimension.draw()];
+    for _ in 0..6 {
+        dimension.step();
+        frames.push(dimension.draw());
+    }
+    frames
+}
+
+/// The `(x, y)` of each active `#` cube in the initial slice.
+pub fn parse(s: &str) -> Result<Vec<(i64, i64)>> {
+    s.lines()
+        .enumerate()
+        .flat_map(|(y, l)| {
+            l.char_indices()
+                .enumerate()
+                .filter_map(move |(x, (i, c))| {
+                    match c {
+                        '#' => Some(Ok((x as i64, y as i64))),
+                        '.' => None,
+                        c   => Some(Err(Diagnostic::new(&l[i..i + c.len_utf8()], format!("invalid character '{}'", c)).into())),
+                    }
+                })
+        })
+        .collect()
+}
+
+/// The active cubes after six cycles in three dimensions.
+pub fn part1(input: &[(i64, i64)]) -> usize {
+    let mut dimension: PocketDimension<Point3> = input.iter().copied().collect();
+    for _ in 0..6 {
+        dimension.step();
+    }
+
+    dimension.active.len()
+}
+
+/// The active cubes after six cycles in four dimensions.
+pub fn part2(input: &[(i64, i64)]) -> usize {
+    let mut dimension: PocketDimension<Point4> = input.iter().copied().collect();
+    for _ in 0..6 {
+        dimension.step();
+    }
+
+    dimension.active.len()
+}
+
+/// An infinite grid of cubes, of which only the active ones are stored.
+pub struct PocketDimension<T: Point>
+{
+    pub active: HashSet<T>,
+}
+
+impl<T: Point> PocketDimension<T>
+{
+    /// Every slice that has an active cube, side by side with increasing z
+    /// and, below each other, increasing w. All slices cover the same x and
+    /// y range.
+    pub fn draw(&self) -> Frame {
+        if self.active.is_empty() {
+            return render::blank(1, 1);
+        }
+
+        let range = |f: &dyn Fn(&T) -> i64| {
+            let min = self.active.iter().map(f).min().unwrap();
+            let max = self.active.iter().map(f).max().unwrap();
+            (min, max - min + 1)
+        };
+        let (x0, width) = range(&|p| p.xy().0);
+        let (y0, height) = range(&|p| p.xy().1);
+        let (z0, nz) = range(&|p| p.zw().0);
+        let (w0, nw) = range(&|p| p.zw().1);
+
+        // Slices are separated by a blank row or column.
+        let origin = |z: i64, w: i64| ((w - w0) * (height + 1), (z - z0) * (width + 1));
+        let mut frame = render::blank(nw * (height + 1) - 1, nz * (width + 1) - 1);
+        for z in z0..z0 + nz {
+            for w in w0..w0 + nw {
+                let (row, col) = origin(z, w);
+                for y in 0..height {
+                    for x in 0..width {
+                        frame[(row + y, col + x)] = Cell::new('.', Rgb(40, 40, 70));
+                    }
+                }
+            }
+        }
+        for p in self.active.iter() {
+            let ((x, y), (z, w)) = (p.xy(), p.zw());
+            let (row, col) = origin(z, w);
+            frame[(row + y - y0, col + x - x0)] = Cell::new('#', Rgb(250, 200, 40));
+        }
+        frame
+    }
+
+    /// Runs one cycle: an active cube stays active with 2 or 3 active
+    /// neighbors, and an inactive cube becomes active with exactly 3.
+    pub fn step(&mut self) {
+        self.active = life::step(&self.active, |active, count| count == 3 || (active && count == 2));
+    }
+}
+
+impl<T: Point> FromIterator<(i64, i64)> for PocketDimension<T> {
+    fn from_iter<I: IntoIterator<Item=(i64, i64)>>(iter: I) -> Self {
+        let active = iter.into_iter().map(T::from_xy).collect();
+        Self { active }
+    }
+}
+
+/// A position in a [`PocketDimension`], whose neighbours are every point
+/// that differs by at most 1 in each coordinate.
+pub trait Point: life::Point {
+    /// The point in the initial slice, with every other coordinate 0.
+    fn from_xy(xy: (i64, i64)) -> Self;
+    fn xy(&self) -> (i64, i64);
+    /// The slice the point is in, with `w` 0 in three dimensions.
+    fn zw(&self) -> (i64, i64);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point for Point3 {
+    fn from_xy((x, y): (i64, i64)) -> Self {
+        Self { x, y, z: 0 }
+    }
+
+    fn xy(&self) -> (i64, i64) {
+        (self.x, self.y)
+    }
+
+    fn zw(&self) -> (i64, i64) {
+        (self.z, 0)
+    }
+}
+
+impl life::Point for Point3 {
+    fn neighbors(&self) -> Vec<Self> {
+        (self.x-1..=self.x+1).flat_map(move |x| {
+            (self.y-1..=self.y+1).flat_map(move |y| {
+                (self.z-1..=self.z+1).map(move |z| Self { x, y, z })
+            })
+        })
+        .filter(|p| p != self)
+        .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point4 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+    pub w: i64,
+}
+
+impl Point for Point4 {
+    fn from_xy((x, y): (i64, i64)) -> Self {
+        Self { x, y, z: 0, w: 0 }
+    }
+
+    fn xy(&self) -> (i64, i64) {
+        (self.x, self.y)
+    }
+
+    fn zw(&self) -> (i64, i64) {
+        (self.z, self.w)
+    }
+}
+
+impl life::Point for Point4 {
+    fn neighbors(&self) -> Vec<Self> {
+        (self.x-1..=self.x+1).flat_map(move |x| {
+            (self.y-1..=self.y+1).flat_map(move |y| {
+                (self.z-1..=self.z+1).flat_map(move |z| {
+                    (self.w-1..=self.w+1).map(move |w| Self { x, y, z, w })
+                })
+            })
+        })
+        .filter(|p| p != self)
+        .collect()
+    }
+}
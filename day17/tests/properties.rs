@@ -0,0 +1,67 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day17::{generate, Day17};
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+/// Runs six cycles in `dims` dimensions by checking every cube in the
+/// bounding box of the active ones.
+fn simulate(input: &str, dims: usize) -> usize {
+    let mut active: HashSet<Vec<i64>> = input.lines()
+        .enumerate()
+        .flat_map(|(y, l)| {
+            l.chars().enumerate().filter(|&(_, c)| c == '#').map(move |(x, _)| {
+                let mut p = vec![0; dims];
+                p[0] = x as i64;
+                p[1] = y as i64;
+                p
+            })
+        })
+        .collect();
+
+    let offsets = cube(&vec![-1; dims], &vec![1; dims]);
+    for _ in 0..6 {
+        if active.is_empty() {
+            break;
+        }
+        let low: Vec<i64> = (0..dims).map(|d| active.iter().map(|p| p[d]).min().unwrap() - 1).collect();
+        let high: Vec<i64> = (0..dims).map(|d| active.iter().map(|p| p[d]).max().unwrap() + 1).collect();
+        active = cube(&low, &high).into_iter()
+            .filter(|p| {
+                let neighbors = offsets.iter()
+                    .filter(|o| o.iter().any(|&d| d != 0))
+                    .filter(|o| active.contains(&p.iter().zip(o.iter()).map(|(a, b)| a + b).collect::<Vec<i64>>()))
+                    .count();
+                neighbors == 3 || (neighbors == 2 && active.contains(p))
+            })
+            .collect();
+    }
+    active.len()
+}
+
+/// Every point from `low` to `high` inclusive.
+fn cube(low: &[i64], high: &[i64]) -> Vec<Vec<i64>> {
+    let mut points = vec![vec![]];
+    for d in 0..low.len() {
+        points = points.into_iter()
+            .flat_map(|p| (low[d]..=high[d]).map(move |v| {
+                let mut p = p.clone();
+                p.push(v);
+                p
+            }))
+            .collect();
+    }
+    points
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn matches_brute_force(seed: u64, size in 0usize..5) {
+        let input = generate::input(&mut rng(seed), size);
+        let cubes = Day17::parse(&input).unwrap();
+        prop_assert_eq!(Day17::part1(&cubes).unwrap(), simulate(&input, 3));
+        prop_assert_eq!(Day17::part2(&cubes).unwrap(), simulate(&input, 4));
+    }
+}
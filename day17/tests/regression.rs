@@ -0,0 +1,41 @@
+use aoc_common::regression::parse_error;
+use aoc_common::render::{self, render, Cell};
+use day17::Day17;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day17>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day17>(".#.\n.x#\n");
+    assert_eq!(d.message(), "invalid character 'x'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 2)));
+}
+
+#[test]
+fn frames() {
+    let example = include_str!("../input_test.txt");
+
+    // The starting slice, then the dimension after each of the six cycles.
+    let frames = render::<Day17>(example, 1).unwrap();
+    assert_eq!(frames.len(), 7);
+    assert_eq!(render::ansi(&frames[0]).matches('#').count(), 5);
+
+    // After one cycle, slices z = -1, 0 and 1 sit side by side with a blank
+    // column between them.
+    let frame = &frames[1];
+    assert_eq!((frame.rows(), frame.cols()), (3, 11));
+    assert_eq!(frame[(0, 0)].glyph, '#');
+    assert_eq!(frame[(0, 1)].glyph, '.');
+    assert_eq!(frame[(0, 3)], Cell::BLANK);
+    assert_eq!(frame[(0, 6)].glyph, '#');
+    assert_eq!(frame.iter().filter(|(_, c)| c.glyph == '#').count(), 11);
+
+    // In four dimensions, the w = -1, 0 and 1 rows of slices are stacked.
+    let frame = &render::<Day17>(example, 2).unwrap()[1];
+    assert_eq!((frame.rows(), frame.cols()), (11, 11));
+    assert_eq!(frame[(3, 0)], Cell::BLANK);
+    assert_eq!(frame.iter().filter(|(_, c)| c.glyph == '#').count(), 29);
+}
@@ -0,0 +1,31 @@
+use aoc_common::generate::{Rng, StdRng};
+
+/// `size` expressions of single digits, `+`, `*` and parentheses, with at
+/// most 12 numbers each so that the answers fit in an `i64`.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| expression(rng, 2, &mut 12) + "\n")
+        .collect()
+}
+
+fn expression(rng: &mut StdRng, depth: usize, budget: &mut usize) -> String {
+    let terms = rng.random_range(2..=4);
+    let mut s = String::new();
+    for i in 0..terms {
+        if i > 0 {
+            if *budget == 0 {
+                break;
+            }
+            s += if rng.random_bool(0.5) { " + " } else { " * " };
+        }
+
+        if depth > 0 && *budget >= 2 && rng.random_bool(0.3) {
+            s += &format!("({})", expression(rng, depth - 1, budget));
+        }
+        else {
+            s += &rng.random_range(1..=9).to_string();
+            *budget = budget.saturating_sub(1);
+        }
+    }
+    s
+}
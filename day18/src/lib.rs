@@ -0,0 +1,258 @@
+//! Day 18: Operation Order. Evaluate expressions under unusual precedence
+//! rules: first strictly left to right, then with `+` binding tighter than
+//! `*`.
+
+use anyhow::{anyhow, Result};
+use aoc_common::diagnostic::{Diagnostic, ResultExt};
+use aoc_common::parse::map_lines;
+use aoc_common::Solver;
+use std::iter::Peekable;
+use std::fmt;
+
+pub mod generate;
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input<'a> = Vec<Program<'a>>;
+    type Answer1 = Literal;
+    type Answer2 = Literal;
+
+    fn parse(input: &str) -> Result<Vec<Program<'_>>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Program<'_>>) -> Result<Literal> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Program<'_>>) -> Result<Literal> {
+        part2(input)
+    }
+}
+
+/// One expression per line, tokenized and checked for syntax errors.
+pub fn parse(s: &str) -> Result<Vec<Program<'_>>> {
+    map_lines(s, tokenize)
+}
+
+/// The sum of every expression's value, evaluated left to right.
+pub fn part1(input: &[Program]) -> Result<Literal> {
+    sum(input, Precedence::Equal)
+}
+
+/// The sum of every expression's value, with additions first.
+pub fn part2(input: &[Program]) -> Result<Literal> {
+    sum(input, Precedence::AddFirst)
+}
+
+fn sum(input: &[Program], precedence: Precedence) -> Result<Literal> {
+    input.iter().try_fold(0, |total: Literal, p| {
+        total.checked_add(eval(p, precedence)?)
+            .ok_or_else(|| anyhow!("the sum of the expressions overflows"))
+    })
+}
+
+/// The value of `program` under the given precedence rules.
+pub fn eval(program: &Program, precedence: Precedence) -> Result<Literal> {
+    parse_program(program, precedence).and_then(|ast| eval_ast(&ast))
+}
+
+
+/// A number, an operator or a parenthesis, as a slice of the input line.
+pub type Token<'a> = &'a str;
+
+/// The tokens of one line of the homework.
+pub struct Program<'a> {
+    line: &'a str,
+    tokens: Vec<Token<'a>>,
+}
+
+/// Splits a line into tokens and checks that they form an expression.
+pub fn tokenize(s: &str) -> Result<Program<'_>> {
+    let mut tokens = vec![];
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            ' ' => (),
+            '+' | '*' | '(' | ')' => tokens.push(&s[i..i + 1]),
+            '0'..='9' => {
+                let mut end = i + 1;
+                while let Some(&(j, '0'..='9')) = chars.peek() {
+                    end = j + 1;
+                    chars.next();
+                }
+                tokens.push(&s[i..end]);
+            },
+            c => return Err(Diagnostic::new(&s[i..i + c.len_utf8()], format!("unexpected character '{}'", c)).into()),
+        }
+    }
+
+    // Check the syntax up front so that errors are reported while parsing.
+    let program = Program { line: s, tokens };
+    parse_program(&program, Precedence::Equal)?;
+    Ok(program)
+}
+
+/// The syntax tree of `program`, with operators binding as `precedence`
+/// says.
+pub fn parse_program(program: &Program, precedence: Precedence) -> Result<Box<Primary>> {
+    let eof = &program.line[program.line.len()..];
+    let mut tokens = program.tokens.iter().copied().peekable();
+    let ast = parse_expression(&mut tokens, precedence, eof)?;
+    match tokens.next() {
+        None => Ok(ast),
+        Some(t) => Err(Diagnostic::new(t, format!("unexpected '{}'", t)).into()),
+    }
+}
+
+fn parse_primary<'a, I>(tokens: &mut Peekable<I>, precedence: Precedence, eof: &str) -> Result<Box<Primary>>
+where I: Iterator<Item = Token<'a>>
+{
+    match tokens.next() {
+        Some("(") => {
+            let e = parse_expression(tokens, precedence, eof)?;
+            match tokens.next() {
+                Some(")") => Ok(e),
+                Some(t) => Err(Diagnostic::new(t, format!("expected ')' but got '{}'", t)).into()),
+                None => Err(Diagnostic::new(eof, "expected ')' but got end of line").into()),
+            }
+        },
+        Some(t) => parse_literal(t),
+        None => Err(Diagnostic::new(eof, "expected a number or '(' but got end of line").into()),
+    }
+}
+
+
+fn parse_expression<'a, I>(tokens: &mut Peekable<I>, precedence: Precedence, eof: &str) -> Result<Box<Primary>>
+where I: Iterator<Item = Token<'a>>
+{
+    let lhs = parse_primary(tokens, precedence, eof)?;
+    parse_expression_1(lhs, tokens, precedence, 0, eof)
+}
+
+fn parse_expression_1<'a, I>(mut lhs: Box<Primary>, tokens: &mut Peekable<I>, precedence: Precedence, min_precedence: usize, eof: &str) -> Result<Box<Primary>>
+where I: Iterator<Item = Token<'a>>
+{
+    loop {
+        let op = match tokens.peek() {
+            None | Some(&")") => break,
+            Some(t) => parse_op(t)?,
+        };
+        if op.precedence(precedence) < min_precedence { break; }
+        tokens.next();
+
+        let mut rhs = parse_primary(tokens, precedence, eof)?;
+        loop {
+            let next_op = match tokens.peek() {
+                None | Some(&")") => break,
+                Some(t) => parse_op(t)?,
+            };
+            if next_op.precedence(precedence) <= op.precedence(precedence) { break; }
+            rhs = parse_expression_1(rhs, tokens, precedence, next_op.precedence(precedence), eof)?;
+        }
+
+        lhs = Box::new(Primary::Expression { op, lhs, rhs });
+    }
+
+    Ok(lhs)
+}
+
+fn parse_literal(t: Token) -> Result<Box<Primary>> {
+    if !t.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(Diagnostic::new(t, format!("expected a number or '(' but got '{}'", t)).into());
+    }
+    Ok(Box::new(Primary::Literal(t.parse().at(t)?)))
+}
+
+
+fn parse_op(t: Token) -> Result<Operation> {
+    match t {
+        "+" => Ok(Operation::Add),
+        "*" => Ok(Operation::Mul),
+        _   => Err(Diagnostic::new(t, format!("expected '+' or '*' but got '{}'", t)).into()),
+    }
+}
+
+
+/// A node of an expression's syntax tree.
+pub enum Primary {
+    Expression { op: Operation, lhs: Box<Primary>, rhs: Box<Primary> },
+    Literal(Literal),
+}
+
+pub type Literal = i64;
+
+/// How tightly the operators bind relative to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precedence {
+    /// `+` and `*` bind equally, so expressions are evaluated left to right.
+    Equal,
+    /// `+` binds tighter than `*`.
+    AddFirst,
+}
+
+pub enum Operation {
+    Add,
+    Mul,
+}
+
+impl Operation {
+    /// `lhs` and `rhs` combined, or an error if that overflows.
+    pub fn eval(&self, lhs: Literal, rhs: Literal) -> Result<Literal> {
+        let val = match self {
+            Operation::Add => lhs.checked_add(rhs),
+            Operation::Mul => lhs.checked_mul(rhs),
+        };
+        val.ok_or_else(|| anyhow!("{} {} {} overflows", lhs, self, rhs))
+    }
+
+    /// Operations with higher precedence bind tighter.
+    pub fn precedence(&self, precedence: Precedence) -> usize {
+        match (self, precedence) {
+            (Operation::Add, Precedence::AddFirst) => 1,
+            _ => 0,
+        }
+    }
+}
+
+
+/// The value of an expression's syntax tree.
+pub fn eval_ast(primary: &Primary) -> Result<Literal> {
+    match primary {
+        Primary::Expression { op, lhs, rhs } => {
+            let lhs_val = eval_ast(lhs)?;
+            let rhs_val = eval_ast(rhs)?;
+            op.eval(lhs_val, rhs_val)
+        },
+        Primary::Literal(l) => Ok(*l),
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Operation::Add => '+',
+            Operation::Mul => '*',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+fn _print_ast(primary: &Primary, level: usize) {
+    let indent = "  ".repeat(level);
+    match primary {
+        Primary::Expression { op, lhs, rhs } => {
+            println!("{}Op: {}", indent, op);
+            println!("{}lhs:", indent);
+            _print_ast(lhs, level+1);
+            println!("{}rhs:", indent);
+            _print_ast(rhs, level+1);
+        },
+
+        Primary::Literal(l) => {
+            println!("{}{}", indent, l);
+        }
+    }
+
+}
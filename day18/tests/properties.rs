@@ -0,0 +1,44 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day18::{generate, Day18};
+use proptest::prelude::*;
+
+/// Evaluates the innermost parentheses first, replacing each with its value
+/// as `eval_flat` works it out.
+fn eval(line: &str, eval_flat: fn(&str) -> i64) -> i64 {
+    let mut line = line.to_string();
+    while let Some(open) = line.rfind('(') {
+        let close = open + line[open..].find(')').unwrap();
+        let value = eval_flat(&line[open + 1..close]);
+        line.replace_range(open..=close, &value.to_string());
+    }
+    eval_flat(&line)
+}
+
+/// Evaluates an expression without parentheses, left to right.
+fn eval_left_to_right(s: &str) -> i64 {
+    let mut tokens = s.split(' ');
+    let mut value = tokens.next().unwrap().parse().unwrap();
+    while let (Some(op), Some(n)) = (tokens.next(), tokens.next()) {
+        let n: i64 = n.parse().unwrap();
+        value = if op == "+" { value + n } else { value * n };
+    }
+    value
+}
+
+/// Evaluates an expression without parentheses, additions first.
+fn eval_add_first(s: &str) -> i64 {
+    s.split(" * ")
+        .map(|sum| sum.split(" + ").map(|n| n.parse::<i64>().unwrap()).sum::<i64>())
+        .product()
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        let programs = Day18::parse(&input).unwrap();
+        prop_assert_eq!(Day18::part1(&programs).unwrap(), input.lines().map(|l| eval(l, eval_left_to_right)).sum::<i64>());
+        prop_assert_eq!(Day18::part2(&programs).unwrap(), input.lines().map(|l| eval(l, eval_add_first)).sum::<i64>());
+    }
+}
@@ -0,0 +1,46 @@
+use aoc_common::regression::parse_error;
+use day18::{eval, tokenize, Day18, Precedence};
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day18>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn examples() {
+    let examples = [
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+        ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 13632, 23340),
+    ];
+    for &(line, equal, add_first) in examples.iter() {
+        let program = tokenize(line).unwrap();
+        assert_eq!(eval(&program, Precedence::Equal).unwrap(), equal, "{}", line);
+        assert_eq!(eval(&program, Precedence::AddFirst).unwrap(), add_first, "{}", line);
+    }
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day18>("1 + (2 * 3\n");
+    assert_eq!(d.message(), "expected ')' but got end of line");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 11)));
+
+    let d = parse_error::<Day18>("2 * 3\n1 + * 2\n");
+    assert_eq!(d.message(), "expected a number or '(' but got '*'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 5)));
+}
+
+#[test]
+fn overflow() {
+    let program = tokenize("9999999999 * 9999999999").unwrap();
+    let e = eval(&program, Precedence::Equal).unwrap_err();
+    assert_eq!(e.to_string(), "9999999999 * 9999999999 overflows");
+
+    let programs = vec![tokenize("9223372036854775807").unwrap(), tokenize("1").unwrap()];
+    assert!(day18::part1(&programs).is_err());
+    assert!(day18::part2(&programs).is_err());
+}
@@ -0,0 +1,75 @@
+use aoc_common::generate::{IndexedRandom, Rng, SliceRandom, StdRng};
+
+/// Rules where `0: 8 11`, `8: 42` and `11: 42 31`, with 42 and 31 each
+/// built from three levels of pairs over `"a"` and `"b"`, followed by
+/// `size` messages. Most messages are runs of 42s then 31s, so that both
+/// parts match some of them, and some have a letter flipped.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut ids: Vec<usize> = (1..=60).filter(|id| ![8, 11, 31, 42].contains(id)).collect();
+    ids.shuffle(rng);
+    let mut ids = ids.into_iter();
+
+    let mut rules = vec!["0: 8 11".to_string(), "8: 42".to_string(), "11: 42 31".to_string()];
+    let mut level: Vec<(usize, Vec<String>)> = ["a", "b"].iter()
+        .map(|&c| {
+            let id = ids.next().unwrap();
+            rules.push(format!("{}: \"{}\"", id, c));
+            (id, vec![c.to_string()])
+        })
+        .collect();
+
+    for targets in [vec![None, None], vec![None, None], vec![Some(42), Some(31)]] {
+        level = targets.into_iter()
+            .map(|target| {
+                let id = target.unwrap_or_else(|| ids.next().unwrap());
+                let (body, language) = pairs(rng, &level);
+                rules.push(format!("{}: {}", id, body));
+                (id, language)
+            })
+            .collect();
+    }
+    rules.shuffle(rng);
+
+    let (words42, words31) = (&level[0].1, &level[1].1);
+    let messages: Vec<String> = (0..size)
+        .map(|_| {
+            let m = rng.random_range(1..=4);
+            let n = rng.random_range(0..=3);
+            let mut message = String::new();
+            for _ in 0..m {
+                message += words42.choose(rng).unwrap();
+            }
+            for _ in 0..n {
+                message += words31.choose(rng).unwrap();
+            }
+            if rng.random_bool(0.2) {
+                let i = rng.random_range(0..message.len());
+                let flipped = if &message[i..=i] == "a" { "b" } else { "a" };
+                message.replace_range(i..=i, flipped);
+            }
+            message
+        })
+        .collect();
+
+    rules.join("\n") + "\n\n" + &messages.join("\n") + "\n"
+}
+
+/// One or two alternatives of two rules from `level`, and the words they
+/// match. Alternatives never match the same word, since the solver tries
+/// every way to match and ambiguous rules would multiply the work.
+fn pairs(rng: &mut StdRng, level: &[(usize, Vec<String>)]) -> (String, Vec<String>) {
+    let mut alternatives = vec![];
+    let mut language: Vec<String> = vec![];
+    for _ in 0..rng.random_range(1..=2) {
+        let (a, b) = (level.choose(rng).unwrap(), level.choose(rng).unwrap());
+        let words: Vec<String> = a.1.iter()
+            .flat_map(|x| b.1.iter().map(move |y| format!("{}{}", x, y)))
+            .collect();
+        if words.iter().any(|w| language.contains(w)) {
+            continue;
+        }
+        alternatives.push(format!("{} {}", a.0, b.0));
+        language.extend(words);
+    }
+    (alternatives.join(" | "), language)
+}
@@ -0,0 +1,238 @@
+//! Day 19: Monster Messages. Check which messages match the rules for
+//! valid messages.
+
+use anyhow::{anyhow, Result};
+use aoc_common::diagnostic::{Diagnostic, ResultExt};
+use aoc_common::parse::map_lines;
+use aoc_common::Solver;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input<'a> = (RuleSet, Vec<&'a str>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(RuleSet, Vec<&'_ str>)> {
+        parse(input)
+    }
+
+    fn part1(input: &(RuleSet, Vec<&'_ str>)) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &(RuleSet, Vec<&'_ str>)) -> Result<usize> {
+        part2(input)
+    }
+}
+
+/// The rules, a blank line, then one message per line.
+pub fn parse(s: &str) -> Result<(RuleSet, Vec<&str>)> {
+    let (rules_str, strs_str) = s.split_once("\n\n")
+        .ok_or_else(|| Diagnostic::new(&s[s.len()..], "expected a blank line and then strings to match"))?;
+    let rule_set: RuleSet = rules_str.parse()?;
+    let strs: Vec<&str> = strs_str.lines().collect();
+    Ok((rule_set, strs))
+}
+
+/// How many messages match rule 0.
+pub fn part1((rule_set, strs): &(RuleSet, Vec<&str>)) -> usize {
+    strs.iter().filter(|s| rule_set.matches(s)).count()
+}
+
+/// How many messages match rule 0 once rules 8 and 11 loop.
+pub fn part2((rule_set, strs): &(RuleSet, Vec<&str>)) -> Result<usize> {
+    if let Some(id) = [42, 31].iter().find(|id| !rule_set.rules.contains_key(id)) {
+        return Err(anyhow!("rules 8 and 11 refer to rule {}, which is missing", id));
+    }
+
+    let mut rule_set = rule_set.clone();
+    rule_set.rules.insert(8, "42 | 42 8".parse().unwrap());
+    rule_set.rules.insert(11, "42 31 | 42 11 31".parse().unwrap());
+
+    Ok(strs.iter().filter(|s| rule_set.matches(s)).count())
+}
+
+/// Rules by number. Every rule that is referred to exists, as does rule 0.
+#[derive(Clone)]
+pub struct RuleSet {
+    pub rules: HashMap<usize, Rule>
+}
+
+impl RuleSet {
+    /// Whether all of `s` matches rule 0.
+    pub fn matches(&self, s: &str) -> bool {
+        let rule_id = 0;
+        !self.matches_helper(s, rule_id, true).is_empty()
+    }
+
+    fn matches_helper(&self, s: &str, rule_id: usize, whole_str: bool) -> Vec<usize> {
+        let rule = self.rules.get(&rule_id).unwrap();
+        let mut res = vec![];
+        for subrule in rule.subrules.iter() {
+            for c in self.matches_helper_2(&subrule[..], s) {
+                if !whole_str || c == s.len() {
+                    res.push(c);
+                }
+            }
+        }
+
+        res
+    }
+
+    fn matches_helper_2(&self, subrule: &[RuleItem], s: &str) -> Vec<usize> {
+        let mut res: Vec<usize> = vec![];
+        if subrule.is_empty() {
+            res.push(0)
+        }
+        else if !s.is_empty() {
+            let item = &subrule[0];
+            match item {
+                RuleItem::Literal(l) => {
+                    if s.starts_with(l.as_str()) {
+                        for c in self.matches_helper_2(&subrule[1..], &s[l.len()..]) {
+                            res.push(c + l.len());
+                        }
+                    }
+                },
+                RuleItem::Rule(id) => {
+                    for c in self.matches_helper(s, *id, false) {
+                        for d in self.matches_helper_2(&subrule[1..], &s[c..]) {
+                            res.push(c+d);
+                        }
+                    }
+                },
+            }
+        }
+
+        res
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let rules: HashMap<usize, Rule> = map_lines(s, |l| {
+            let (id, body) = l.split_once(':').ok_or_else(|| Diagnostic::new(l, "expected a rule like '0: 1 2 | \"a\"'"))?;
+            let id: usize = id.parse().at(id)?;
+            let rule: Rule = body.parse()?;
+            Ok::<_, anyhow::Error>((id, rule))
+        })?
+        .into_iter()
+        .collect();
+
+        if !rules.contains_key(&0) {
+            return Err(Diagnostic::new(s, "missing rule 0").into());
+        }
+
+        // Rule bodies are parsed into owned items, so check references
+        // against the text to be able to point at them.
+        let items = s.lines()
+            .filter_map(|l| l.split_once(':'))
+            .flat_map(|(_, body)| body.split_whitespace());
+        for item in items {
+            if let Ok(id) = item.parse::<usize>() {
+                if !rules.contains_key(&id) {
+                    return Err(Diagnostic::new(item, format!("no rule {}", id)).into());
+                }
+            }
+        }
+
+        // Matching a rule that reaches itself before consuming anything
+        // would recurse forever.
+        let nullable = nullable_rules(&rules);
+        for id_str in s.lines().filter_map(|l| l.split_once(':')).map(|(id, _)| id) {
+            let id: usize = id_str.parse().unwrap();
+            if reaches_without_consuming(&rules, &nullable, id, id) {
+                return Err(Diagnostic::new(id_str, format!("rule {} can match itself without consuming input", id)).into());
+            }
+        }
+
+        Ok(RuleSet{ rules })
+    }
+}
+
+/// The rules that can match the empty string.
+fn nullable_rules(rules: &HashMap<usize, Rule>) -> HashSet<usize> {
+    let mut nullable = HashSet::new();
+    loop {
+        let before = nullable.len();
+        for (&id, rule) in rules.iter() {
+            if rule.subrules.iter().any(|subrule| subrule.iter().all(|item| item_nullable(item, &nullable))) {
+                nullable.insert(id);
+            }
+        }
+        if nullable.len() == before {
+            return nullable;
+        }
+    }
+}
+
+fn item_nullable(item: &RuleItem, nullable: &HashSet<usize>) -> bool {
+    match item {
+        RuleItem::Literal(l) => l.is_empty(),
+        RuleItem::Rule(id) => nullable.contains(id),
+    }
+}
+
+/// Whether matching rule `from` can start matching rule `to` at the same
+/// position.
+fn reaches_without_consuming(rules: &HashMap<usize, Rule>, nullable: &HashSet<usize>, from: usize, to: usize) -> bool {
+    let mut seen = HashSet::new();
+    let mut stack = vec![from];
+    while let Some(id) = stack.pop() {
+        for subrule in rules[&id].subrules.iter() {
+            for item in subrule.iter() {
+                if let RuleItem::Rule(next) = item {
+                    if *next == to {
+                        return true;
+                    }
+                    if seen.insert(*next) {
+                        stack.push(*next);
+                    }
+                }
+                if !item_nullable(item, nullable) {
+                    break;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Alternatives, each a sequence of items that must match in order.
+#[derive(Clone)]
+pub struct Rule {
+    pub subrules: Vec<Vec<RuleItem>>
+}
+
+impl FromStr for Rule {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let subrules = s.split('|').map(|subrule_str| subrule_str.trim().split(" ").map(|item_str| item_str.parse().at(item_str)).collect()).collect::<Result<_>>()?;
+        Ok(Self { subrules })
+    }
+}
+
+#[derive(Clone)]
+pub enum RuleItem {
+    Rule(usize),
+    Literal(String),
+}
+
+impl FromStr for RuleItem {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(literal) = s.split('"').nth(1) {
+            Ok(RuleItem::Literal(literal.to_string()))
+        }
+        else {
+            let id = s.parse()?;
+            Ok(RuleItem::Rule(id))
+        }
+    }
+}
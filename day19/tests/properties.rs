@@ -0,0 +1,62 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day19::{generate, Day19};
+use proptest::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Every word `id` matches, assuming the rules have no loops.
+fn language(rules: &HashMap<&str, &str>, id: &str) -> HashSet<String> {
+    let body = rules[id];
+    if let Some(literal) = body.strip_prefix('"') {
+        return vec![literal.trim_end_matches('"').to_string()].into_iter().collect();
+    }
+    body.split(" | ")
+        .flat_map(|alternative| {
+            alternative.split(' ').fold(vec![String::new()], |words, item| {
+                let suffixes = language(rules, item);
+                words.iter().flat_map(|w| suffixes.iter().map(move |s| format!("{}{}", w, s))).collect()
+            })
+        })
+        .collect()
+}
+
+/// Whether `s` is `m` words of `words42` followed by `n` words of
+/// `words31`, for some `m` and `n` that `accept` allows.
+fn matches(s: &str, words42: &HashSet<String>, words31: &HashSet<String>, accept: fn(usize, usize) -> bool) -> bool {
+    // Every (position, count) reachable by matching whole words from the start.
+    let runs = |start: &[(usize, usize)], words: &HashSet<String>| {
+        let mut reached: Vec<(usize, usize)> = start.to_vec();
+        let mut i = 0;
+        while i < reached.len() {
+            let (at, count) = reached[i];
+            for w in words.iter() {
+                if s[at..].starts_with(w.as_str()) && !reached.contains(&(at + w.len(), count + 1)) {
+                    reached.push((at + w.len(), count + 1));
+                }
+            }
+            i += 1;
+        }
+        reached
+    };
+
+    runs(&[(0, 0)], words42).into_iter()
+        .any(|(at, m)| runs(&[(at, 0)], words31).into_iter().any(|(end, n)| end == s.len() && accept(m, n)))
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        let parsed = Day19::parse(&input).unwrap();
+
+        let (rules, messages) = input.split_once("\n\n").unwrap();
+        let rules: HashMap<&str, &str> = rules.lines().map(|l| l.split_once(": ").unwrap()).collect();
+        let words42 = language(&rules, "42");
+        let words31 = language(&rules, "31");
+
+        let part1 = messages.lines().filter(|s| matches(s, &words42, &words31, |m, n| m == 2 && n == 1)).count();
+        prop_assert_eq!(Day19::part1(&parsed).unwrap(), part1);
+        let part2 = messages.lines().filter(|s| matches(s, &words42, &words31, |m, n| m > n && n >= 1)).count();
+        prop_assert_eq!(Day19::part2(&parsed).unwrap(), part2);
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::regression::parse_error;
+use day19::Day19;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day19>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day19>("42: 9 14 | \n\nab\n");
+    assert_eq!(d.message(), "cannot parse integer from empty string");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 11)));
+}
+
+#[test]
+fn left_recursive() {
+    let d = parse_error::<Day19>("0: 1 1\n1: 0 | \"a\"\n\naa\n");
+    assert_eq!(d.message(), "rule 0 can match itself without consuming input");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 1)));
+
+    let d = parse_error::<Day19>("0: \"a\" 1\n1: 2 1 | \"b\"\n2: \"\" | \"a\"\n\nab\n");
+    assert_eq!(d.message(), "rule 1 can match itself without consuming input");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((2, 1)));
+
+    // Rules 8 and 11 only loop after matching rule 42 first.
+    let input = "0: 8 11\n8: 42 | 42 8\n11: 42 31 | 42 11 31\n42: \"a\"\n31: \"b\"\n\naab\nabb\n";
+    let parsed = day19::parse(input).unwrap();
+    assert_eq!(day19::part1(&parsed), 1);
+    assert_eq!(day19::part2(&parsed).unwrap(), 1);
+}
@@ -0,0 +1,101 @@
+use crate::{Pixel, Tile, SEA_MONSTER};
+use aoc_common::generate::{IndexedRandom, Rng, SliceRandom, StdRng};
+use aoc_common::grid::Grid;
+use std::collections::HashSet;
+
+/// Pixels along each side of a tile, as in the real puzzle.
+const TILE: i64 = 10;
+
+/// About `size` tiles (a square of 2 to 4 a side) cut from a random image
+/// with some sea monsters in it, then shuffled, turned and flipped.
+/// Neighbouring tiles share their edge pixels, and no other two edges match
+/// in any orientation, so the tiles only fit together one way.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let side = ((size as f64).sqrt() as i64).clamp(2, 4);
+    let width = side * (TILE - 1) + 1;
+
+    let mut pixels = loop {
+        let cells = (0..width)
+            .flat_map(|r| (0..width).map(move |c| (r, c)))
+            .map(|(r, c)| {
+                // Sparse insides make monsters that weren't put there unlikely.
+                let on = if r % (TILE - 1) == 0 || c % (TILE - 1) == 0 { 0.5 } else { 0.25 };
+                if rng.random_bool(on) { Pixel::On } else { Pixel::Off }
+            })
+            .collect();
+        let pixels = Grid::new(width, width, cells);
+        if edges_are_unique(&pixels, side) {
+            break pixels;
+        }
+    };
+
+    add_sea_monsters(rng, &mut pixels, side);
+
+    let mut ids: Vec<u64> = (1000..10000).collect();
+    ids.shuffle(rng);
+    let mut tiles: Vec<Tile> = (0..side)
+        .flat_map(|row| (0..side).map(move |col| (row, col)))
+        .zip(ids)
+        .map(|((row, col), id)| {
+            let cells = (0..TILE)
+                .flat_map(|r| (0..TILE).map(move |c| (r, c)))
+                .map(|(r, c)| pixels[(row * (TILE - 1) + r, col * (TILE - 1) + c)])
+                .collect();
+            let orientations = Grid::new(TILE, TILE, cells).orientations();
+            Tile { id, pixels: orientations.choose(rng).unwrap().clone() }
+        })
+        .collect();
+    tiles.shuffle(rng);
+
+    let tiles: Vec<String> = tiles.iter().map(|t| t.to_string()).collect();
+    tiles.join("\n")
+}
+
+/// Whether every tile edge in `pixels`, read either way, lies on a
+/// different stretch of the lines between tiles.
+fn edges_are_unique(pixels: &Grid<Pixel>, side: i64) -> bool {
+    let mut edges = HashSet::new();
+    for line in 0..=side {
+        for tile in 0..side {
+            let along = |i| tile * (TILE - 1) + i;
+            let across = line * (TILE - 1);
+            let horizontal: Vec<Pixel> = (0..TILE).map(|i| pixels[(across, along(i))]).collect();
+            let vertical: Vec<Pixel> = (0..TILE).map(|i| pixels[(along(i), across)]).collect();
+            for edge in [horizontal, vertical].iter() {
+                let reversed: Vec<Pixel> = edge.iter().rev().copied().collect();
+                if !edges.insert(edge.clone().min(reversed)) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Draws up to `side` sea monsters in the image inside the tiles' borders,
+/// without overlapping.
+fn add_sea_monsters(rng: &mut StdRng, pixels: &mut Grid<Pixel>, side: i64) {
+    let inner = TILE - 2;
+    let size = side * inner;
+    let (height, width) = (SEA_MONSTER.len() as i64, SEA_MONSTER[0].len() as i64);
+    if width > size {
+        return;
+    }
+
+    let mut taken = HashSet::new();
+    for _ in 0..side {
+        let (row, col) = (rng.random_range(0..=size - height), rng.random_range(0..=size - width));
+        let monster: Vec<(i64, i64)> = SEA_MONSTER.iter()
+            .enumerate()
+            .flat_map(|(r, line)| line.char_indices().filter(|&(_, c)| c == '#').map(move |(c, _)| (row + r as i64, col + c as i64)))
+            .collect();
+        if monster.iter().any(|coord| taken.contains(coord)) {
+            continue;
+        }
+
+        for &(r, c) in monster.iter() {
+            pixels[(r / inner * (TILE - 1) + r % inner + 1, c / inner * (TILE - 1) + c % inner + 1)] = Pixel::On;
+        }
+        taken.extend(monster);
+    }
+}
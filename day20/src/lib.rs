@@ -0,0 +1,293 @@
+//! Day 20: Jurassic Jigsaw. Put the camera array's tiles back together and
+//! look for sea monsters in the image.
+
+use anyhow::{anyhow, Result};
+use aoc_common::combinator::{complete, number, tag, PResult};
+use aoc_common::diagnostic::Diagnostic;
+use aoc_common::grid::{Coord, FromChar, Grid, ToChar};
+use aoc_common::Solver;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+pub mod generate;
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input<'a> = Vec<Tile>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Tile>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Tile>) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Tile>) -> Result<usize> {
+        part2(input)
+    }
+}
+
+/// Tiles like `Tile 2311:` followed by a square of pixels, separated by
+/// blank lines. There must be a square number of tiles, all the same size.
+pub fn parse(s: &str) -> Result<Vec<Tile>> {
+    let mut tiles: Vec<Tile> = vec![];
+    for section in s.split("\n\n").filter(|section| !section.trim().is_empty()) {
+        let tile = Tile::parse(section)?;
+        if let Some(first) = tiles.first() {
+            if tile.pixels.rows() != first.pixels.rows() {
+                let header = section.lines().next().unwrap();
+                return Err(Diagnostic::new(header, format!("tile {} is {} pixels wide, expected {} like tile {}",
+                    tile.id, tile.pixels.rows(), first.pixels.rows(), first.id)).into());
+            }
+        }
+        tiles.push(tile);
+    }
+
+    if tiles.is_empty() {
+        return Err(anyhow!("no tiles"));
+    }
+    if side(tiles.len()).is_none() {
+        return Err(anyhow!("{} tiles can't make a square image", tiles.len()));
+    }
+    Ok(tiles)
+}
+
+/// The product of the IDs of the four corner tiles.
+pub fn part1(tiles: &[Tile]) -> Result<u64> {
+    Ok(assemble(tiles)?.corners().iter().product())
+}
+
+/// How many `#` pixels aren't part of a sea monster.
+pub fn part2(tiles: &[Tile]) -> Result<usize> {
+    Ok(roughness(&assemble(tiles)?.image()))
+}
+
+/// The number of tiles along each side of a square of `tiles` tiles.
+fn side(tiles: usize) -> Option<usize> {
+    let side = (tiles as f64).sqrt().round() as usize;
+    if side * side == tiles { Some(side) } else { None }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Pixel {
+    Off,
+    On,
+}
+
+impl FromChar for Pixel {
+    type Err = anyhow::Error;
+
+    fn from_char(c: &char) -> Result<Self> {
+        match c {
+            '.' => Ok(Pixel::Off),
+            '#' => Ok(Pixel::On),
+            _   => Err(anyhow!("invalid pixel '{}', expected '.' or '#'", c)),
+        }
+    }
+}
+
+impl ToChar for Pixel {
+    fn to_char(&self) -> char {
+        match self {
+            Pixel::Off => '.',
+            Pixel::On => '#',
+        }
+    }
+}
+
+/// One camera's square of the image, borders included.
+pub struct Tile {
+    pub id: u64,
+    pub pixels: Grid<Pixel>,
+}
+
+impl Tile {
+    /// Parses a `Tile <id>:` line and the pixels under it.
+    pub fn parse(s: &str) -> Result<Self> {
+        let (header, pixels) = s.split_once('\n').unwrap_or((s, &s[s.len()..]));
+        let id = complete(tile_id, header)?;
+        let pixels: Grid<Pixel> = pixels.parse()?;
+        if pixels.rows() != pixels.cols() {
+            return Err(Diagnostic::new(header, format!("tile {} is {}x{} pixels, expected a square", id, pixels.rows(), pixels.cols())).into());
+        }
+        if pixels.rows() < 3 {
+            // The image is what's left inside each tile's border.
+            return Err(Diagnostic::new(header, format!("tile {} is {}x{} pixels, expected at least 3x3", id, pixels.rows(), pixels.cols())).into());
+        }
+        Ok(Self { id, pixels })
+    }
+}
+
+fn tile_id(s: &str) -> PResult<'_, u64> {
+    let (_, s) = tag("Tile ")(s)?;
+    let (id, s) = number(s)?;
+    let (_, s) = tag(":")(s)?;
+    Ok((id, s))
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tile {}:\n{}", self.id, self.pixels)
+    }
+}
+
+/// The pixels along each edge of an oriented tile, read left to right or
+/// top to bottom.
+fn top(pixels: &Grid<Pixel>) -> Vec<Pixel> {
+    (0..pixels.cols()).map(|c| pixels[(0, c)]).collect()
+}
+
+fn bottom(pixels: &Grid<Pixel>) -> Vec<Pixel> {
+    (0..pixels.cols()).map(|c| pixels[(pixels.rows() - 1, c)]).collect()
+}
+
+fn left(pixels: &Grid<Pixel>) -> Vec<Pixel> {
+    (0..pixels.rows()).map(|r| pixels[(r, 0)]).collect()
+}
+
+fn right(pixels: &Grid<Pixel>) -> Vec<Pixel> {
+    (0..pixels.rows()).map(|r| pixels[(r, pixels.cols() - 1)]).collect()
+}
+
+/// The tiles in a square, row by row, each turned so that its edges match
+/// its neighbours'.
+pub struct Arrangement {
+    side: usize,
+    tiles: Vec<(u64, Grid<Pixel>)>,
+}
+
+impl Arrangement {
+    /// The IDs of the top left, top right, bottom left and bottom right
+    /// tiles.
+    pub fn corners(&self) -> [u64; 4] {
+        let n = self.side;
+        [self.tiles[0].0, self.tiles[n - 1].0, self.tiles[n * (n - 1)].0, self.tiles[n * n - 1].0]
+    }
+
+    /// The whole image, without the tiles' borders.
+    pub fn image(&self) -> Grid<Pixel> {
+        let inner = self.tiles[0].1.rows() - 2;
+        let size = self.side as i64 * inner;
+        let cells = (0..size)
+            .flat_map(|r| (0..size).map(move |c| (r, c)))
+            .map(|(r, c)| {
+                let (_, pixels) = &self.tiles[(r / inner) as usize * self.side + (c / inner) as usize];
+                pixels[(r % inner + 1, c % inner + 1)]
+            })
+            .collect();
+        Grid::new(size, size, cells)
+    }
+}
+
+/// Every orientation of every tile, indexed by left and top edge.
+struct Pieces<'a> {
+    tiles: &'a [Tile],
+    orientations: Vec<Vec<Grid<Pixel>>>,
+    by_left: HashMap<Vec<Pixel>, Vec<(usize, usize)>>,
+    by_top: HashMap<Vec<Pixel>, Vec<(usize, usize)>>,
+}
+
+/// Finds an arrangement of `tiles` where every pair of neighbouring edges
+/// match, trying every orientation of every tile.
+pub fn assemble(tiles: &[Tile]) -> Result<Arrangement> {
+    let side = side(tiles.len()).ok_or_else(|| anyhow!("{} tiles can't make a square image", tiles.len()))?;
+
+    let orientations: Vec<Vec<Grid<Pixel>>> = tiles.iter().map(|t| t.pixels.orientations()).collect();
+    let mut by_left: HashMap<_, Vec<_>> = HashMap::new();
+    let mut by_top: HashMap<_, Vec<_>> = HashMap::new();
+    for (i, tile) in orientations.iter().enumerate() {
+        for (o, pixels) in tile.iter().enumerate() {
+            by_left.entry(left(pixels)).or_default().push((i, o));
+            by_top.entry(top(pixels)).or_default().push((i, o));
+        }
+    }
+
+    let pieces = Pieces { tiles, orientations, by_left, by_top };
+    let mut placed = Vec::with_capacity(tiles.len());
+    let mut used = vec![false; tiles.len()];
+    if !pieces.place(side, &mut placed, &mut used) {
+        return Err(anyhow!("the tiles don't fit together into a square"));
+    }
+
+    let tiles = placed.into_iter()
+        .map(|(i, o)| (pieces.tiles[i].id, pieces.orientations[i][o].clone()))
+        .collect();
+    Ok(Arrangement { side, tiles })
+}
+
+impl Pieces<'_> {
+    /// Fills the rest of the square row by row, backtracking when no tile
+    /// fits.
+    fn place(&self, side: usize, placed: &mut Vec<(usize, usize)>, used: &mut Vec<bool>) -> bool {
+        let n = placed.len();
+        if n == side * side {
+            return true;
+        }
+
+        let (row, col) = (n / side, n % side);
+        let pixels = |(i, o): (usize, usize)| &self.orientations[i][o];
+        let above = if row > 0 { Some(bottom(pixels(placed[n - side]))) } else { None };
+        let candidates: Vec<(usize, usize)> = if col > 0 {
+            self.by_left.get(&right(pixels(placed[n - 1]))).cloned().unwrap_or_default()
+        }
+        else if let Some(above) = &above {
+            self.by_top.get(above).cloned().unwrap_or_default()
+        }
+        else {
+            (0..self.tiles.len()).flat_map(|i| (0..8).map(move |o| (i, o))).collect()
+        };
+
+        for (i, o) in candidates {
+            if used[i] || above.as_ref().is_some_and(|above| *above != top(pixels((i, o)))) {
+                continue;
+            }
+
+            placed.push((i, o));
+            used[i] = true;
+            if self.place(side, placed, used) {
+                return true;
+            }
+            placed.pop();
+            used[i] = false;
+        }
+
+        false
+    }
+}
+
+/// The `#`s of a sea monster.
+pub const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// The pixels of every sea monster in `image`, which must be the right
+/// way round.
+pub fn sea_monsters(image: &Grid<Pixel>) -> HashSet<Coord> {
+    let shape: Vec<Coord> = SEA_MONSTER.iter()
+        .enumerate()
+        .flat_map(|(r, line)| line.char_indices().filter(|&(_, c)| c == '#').map(move |(c, _)| (r as i64, c as i64)))
+        .collect();
+
+    image.coords()
+        .map(|(r, c)| shape.iter().map(|&(dr, dc)| (r + dr, c + dc)).collect::<Vec<_>>())
+        .filter(|monster| monster.iter().all(|coord| image.get(coord) == Some(&Pixel::On)))
+        .flatten()
+        .collect()
+}
+
+/// The `#`s that aren't part of a sea monster, in whichever orientation
+/// of `image` shows the most monster.
+pub fn roughness(image: &Grid<Pixel>) -> usize {
+    let on = image.iter().filter(|&(_, &p)| p == Pixel::On).count();
+    let monsters = image.orientations().iter()
+        .map(|image| sea_monsters(image).len())
+        .max()
+        .unwrap_or(0);
+    on - monsters
+}
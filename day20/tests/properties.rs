@@ -0,0 +1,132 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day20::{generate, Day20, SEA_MONSTER};
+use proptest::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+type Pixels = Vec<Vec<char>>;
+
+fn parse(input: &str) -> Vec<(u64, Pixels)> {
+    input.split("\n\n")
+        .map(|tile| {
+            let mut lines = tile.lines();
+            let id = lines.next().unwrap().trim_start_matches("Tile ").trim_end_matches(':').parse().unwrap();
+            (id, lines.map(|l| l.chars().collect()).collect())
+        })
+        .collect()
+}
+
+fn orientations(pixels: &Pixels) -> Vec<Pixels> {
+    let n = pixels.len();
+    let mut pixels = pixels.clone();
+    let mut all = vec![];
+    for _ in 0..4 {
+        all.push(pixels.iter().map(|row| row.iter().rev().copied().collect()).collect());
+        pixels = (0..n).map(|r| (0..n).map(|c| pixels[n - 1 - c][r]).collect()).collect();
+        all.push(pixels.clone());
+    }
+    all
+}
+
+/// Top, bottom, left and right.
+fn edges(pixels: &Pixels) -> [String; 4] {
+    let n = pixels.len();
+    [
+        pixels[0].iter().collect(),
+        pixels[n - 1].iter().collect(),
+        pixels.iter().map(|row| row[0]).collect(),
+        pixels.iter().map(|row| row[n - 1]).collect(),
+    ]
+}
+
+fn canonical(edge: &str) -> String {
+    let reversed: String = edge.chars().rev().collect();
+    edge.to_string().min(reversed)
+}
+
+/// Since the generator only lets neighbouring edges match, the corners are
+/// the tiles with two edges that match nothing, and each spot has only one
+/// tile that fits against the tiles above and to the left of it, or the
+/// edge of the image.
+fn reference(input: &str) -> (u64, usize) {
+    let tiles = parse(input);
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for (_, pixels) in tiles.iter() {
+        for edge in edges(pixels).iter() {
+            *counts.entry(canonical(edge)).or_default() += 1;
+        }
+    }
+    let unmatched = |edge: &String| counts[&canonical(edge)] == 1;
+    let corners: Vec<usize> = (0..tiles.len())
+        .filter(|&i| edges(&tiles[i].1).iter().filter(|e| unmatched(e)).count() == 2)
+        .collect();
+    let part1 = corners.iter().map(|&i| tiles[i].0).product();
+
+    let side = (tiles.len() as f64).sqrt() as usize;
+    let first = orientations(&tiles[corners[0]].1).into_iter()
+        .find(|p| { let [top, _, left, _] = edges(p); unmatched(&top) && unmatched(&left) })
+        .unwrap();
+    let mut placed = vec![first];
+    let mut used = HashSet::new();
+    used.insert(corners[0]);
+    for n in 1..side * side {
+        let (i, pixels) = (0..tiles.len())
+            .filter(|i| !used.contains(i))
+            .flat_map(|i| orientations(&tiles[i].1).into_iter().map(move |p| (i, p)))
+            .find(|(_, p)| {
+                let [top, _, left, _] = edges(p);
+                let left_fits = if n % side > 0 { left == edges(&placed[n - 1])[3] } else { unmatched(&left) };
+                let top_fits = if n >= side { top == edges(&placed[n - side])[1] } else { unmatched(&top) };
+                left_fits && top_fits
+            })
+            .unwrap();
+        used.insert(i);
+        placed.push(pixels);
+    }
+
+    let inner = placed[0].len() - 2;
+    let image: Pixels = (0..side * inner)
+        .map(|r| (0..side * inner).map(|c| placed[r / inner * side + c / inner][r % inner + 1][c % inner + 1]).collect())
+        .collect();
+    let on = image.iter().flatten().filter(|&&p| p == '#').count();
+    let monsters = orientations(&image).iter()
+        .map(|image| {
+            let mut cells = HashSet::new();
+            for r in 0..image.len().saturating_sub(SEA_MONSTER.len() - 1) {
+                for c in 0..image.len().saturating_sub(SEA_MONSTER[0].len() - 1) {
+                    let monster: Vec<(usize, usize)> = SEA_MONSTER.iter()
+                        .enumerate()
+                        .flat_map(|(dr, line)| line.char_indices().filter(|&(_, ch)| ch == '#').map(move |(dc, _)| (r + dr, c + dc)))
+                        .collect();
+                    if monster.iter().all(|&(r, c)| image[r][c] == '#') {
+                        cells.extend(monster);
+                    }
+                }
+            }
+            cells.len()
+        })
+        .max()
+        .unwrap();
+    (part1, on - monsters)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        let tiles = Day20::parse(&input).unwrap();
+        let tiles: Vec<String> = tiles.iter().map(|t| t.to_string()).collect();
+        prop_assert_eq!(tiles.join("\n"), input);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        let tiles = Day20::parse(&input).unwrap();
+        let (part1, part2) = reference(&input);
+        prop_assert_eq!(Day20::part1(&tiles).unwrap(), part1);
+        prop_assert_eq!(Day20::part2(&tiles).unwrap(), part2);
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::regression::parse_error;
+use day20::Day20;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day20>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn malformed() {
+    let d = parse_error::<Day20>("Tile 1:\n#\n");
+    assert_eq!(d.message(), "tile 1 is 1x1 pixels, expected at least 3x3");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 1)));
+
+    let d = parse_error::<Day20>("Tile 1:\n#.\n.#\n");
+    assert_eq!(d.message(), "tile 1 is 2x2 pixels, expected at least 3x3");
+
+    let d = parse_error::<Day20>("Tile 1:\n#..\n.#.\n");
+    assert_eq!(d.message(), "tile 1 is 2x3 pixels, expected a square");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 1)));
+
+    let d = parse_error::<Day20>("Tile x:\n#..\n");
+    assert_eq!(d.message(), "expected a number, found 'x'");
+    assert_eq!(d.location().map(|l| (l.line, l.column)), Some((1, 6)));
+}
@@ -0,0 +1,62 @@
+use aoc_common::generate::{lines, word, IndexedRandom, Rng, SliceRandom, StdRng};
+use std::collections::HashSet;
+
+const ALLERGENS: [&str; 8] = ["dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat"];
+
+/// About `size` foods made from twice as many ingredients, up to 8 of which
+/// contain an allergen. Foods list some of the allergens in them, and each
+/// allergen's ingredient is the only one in every food that lists it.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let allergens: Vec<&str> = {
+        let count = rng.random_range(1..=ALLERGENS.len().min(size.max(1)));
+        let mut allergens: Vec<&str> = ALLERGENS.choose_multiple(rng, count).copied().collect();
+        allergens.sort_unstable();
+        allergens
+    };
+
+    let mut names = HashSet::new();
+    while names.len() < allergens.len() + 2 * size.max(1) {
+        let len = rng.random_range(3..=8);
+        names.insert(word(rng, len, "abcdefghijklmnopqrstuvwxyz"));
+    }
+    let mut ingredients: Vec<String> = names.into_iter().collect();
+    ingredients.sort_unstable();
+    ingredients.shuffle(rng);
+    let (dangerous, safe) = ingredients.split_at(allergens.len());
+
+    let mut foods: Vec<(HashSet<&str>, Vec<&str>)> = (0..size)
+        .map(|_| {
+            let listed: Vec<usize> = (0..allergens.len()).filter(|_| rng.random_bool(0.3)).collect();
+            let mut food: HashSet<&str> = dangerous.iter().enumerate()
+                .filter(|&(a, _)| listed.contains(&a) || rng.random_bool(0.2))
+                .map(|(_, i)| i.as_str())
+                .collect();
+            food.extend(safe.iter().filter(|_| rng.random_bool(0.4)).map(|i| i.as_str()));
+            (food, listed.iter().map(|&a| allergens[a]).collect::<Vec<_>>())
+        })
+        .filter(|(food, listed)| !food.is_empty() && !listed.is_empty())
+        .collect();
+
+    // One more food per allergen that only has its ingredient in common
+    // with the others listing it.
+    for (a, &allergen) in allergens.iter().enumerate() {
+        let common: Option<HashSet<&str>> = foods.iter()
+            .filter(|(_, listed)| listed.contains(&allergen))
+            .map(|(food, _)| food.clone())
+            .reduce(|common, food| &common & &food);
+        let food: HashSet<&str> = safe.iter()
+            .map(|i| i.as_str())
+            .filter(|i| common.as_ref().is_some_and(|c| !c.contains(i)) && rng.random_bool(0.4))
+            .chain(std::iter::once(dangerous[a].as_str()))
+            .collect();
+        foods.push((food, vec![allergen]));
+    }
+    foods.shuffle(rng);
+
+    lines(foods.into_iter().map(|(food, listed)| {
+        let mut food: Vec<&str> = food.into_iter().collect();
+        food.sort_unstable();
+        food.shuffle(rng);
+        format!("{} (contains {})", food.join(" "), listed.join(", "))
+    }))
+}
@@ -0,0 +1,106 @@
+//! Day 21: Allergen Assessment. Work out which ingredient contains which
+//! allergen from the foods' labels.
+
+use anyhow::{anyhow, Result};
+use aoc_common::assign::assign;
+use aoc_common::combinator::{complete, many1, separated_list1, tag, take_while1, PResult};
+use aoc_common::parse::map_lines;
+use aoc_common::Solver;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+pub mod generate;
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input<'a> = Vec<Food<'a>>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Food<'_>>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Food<'_>>) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Food<'_>>) -> Result<String> {
+        part2(input)
+    }
+}
+
+/// One food per line, like `mxmxvkd kfcds sqjhc (contains dairy, fish)`.
+pub fn parse(input: &str) -> Result<Vec<Food<'_>>> {
+    map_lines(input, Food::parse)
+}
+
+/// How many times ingredients that can't contain any allergen appear.
+pub fn part1(foods: &[Food]) -> usize {
+    let candidates = candidates(foods);
+    let unsafe_ingredients: HashSet<&str> = candidates.values().flatten().copied().collect();
+    foods.iter()
+        .flat_map(|food| food.ingredients.iter())
+        .filter(|i| !unsafe_ingredients.contains(*i))
+        .count()
+}
+
+/// The ingredient containing each allergen, comma separated and sorted by
+/// allergen.
+pub fn part2(foods: &[Food]) -> Result<String> {
+    let mapping: BTreeMap<&str, &str> = assign(&candidates(foods))
+        .ok_or_else(|| anyhow!("no assignment of ingredients to allergens fits every food"))?
+        .into_iter()
+        .collect();
+
+    let dangerous: Vec<&str> = mapping.values().copied().collect();
+    Ok(dangerous.join(","))
+}
+
+/// A food's ingredients, and some of the allergens it contains.
+pub struct Food<'a> {
+    pub ingredients: Vec<&'a str>,
+    pub allergens: Vec<&'a str>,
+}
+
+impl<'a> Food<'a> {
+    /// Parses a line like `mxmxvkd kfcds sqjhc (contains dairy, fish)`.
+    pub fn parse(s: &'a str) -> Result<Self> {
+        complete(Self::line, s)
+    }
+
+    fn line(s: &'a str) -> PResult<'a, Self> {
+        let word = || take_while1("a word", |c| c.is_ascii_lowercase());
+        let (ingredients, s) = many1(|s| {
+            let (ingredient, s) = word()(s)?;
+            let (_, s) = tag(" ")(s)?;
+            Ok((ingredient, s))
+        })(s)?;
+        let (_, s) = tag("(contains ")(s)?;
+        let (allergens, s) = separated_list1(word(), tag(", "))(s)?;
+        let (_, s) = tag(")")(s)?;
+        Ok((Food { ingredients, allergens }, s))
+    }
+}
+
+impl fmt::Display for Food<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (contains {})", self.ingredients.join(" "), self.allergens.join(", "))
+    }
+}
+
+/// The ingredients each allergen could be in: those in every food that
+/// lists it.
+pub fn candidates<'a>(foods: &[Food<'a>]) -> HashMap<&'a str, HashSet<&'a str>> {
+    let mut candidates: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for food in foods {
+        let ingredients: HashSet<&str> = food.ingredients.iter().copied().collect();
+        for &allergen in food.allergens.iter() {
+            candidates.entry(allergen)
+                .and_modify(|c| c.retain(|i| ingredients.contains(i)))
+                .or_insert_with(|| ingredients.clone());
+        }
+    }
+    candidates
+}
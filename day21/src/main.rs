@@ -0,0 +1,6 @@
+use anyhow::Result;
+use day21::Day21;
+
+fn main() -> Result<()> {
+    aoc_common::main::<Day21>(21)
+}
@@ -0,0 +1,65 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day21::{generate, Day21};
+use proptest::prelude::*;
+
+fn parse(input: &str) -> Vec<(Vec<&str>, Vec<&str>)> {
+    input.lines()
+        .map(|l| {
+            let (ingredients, allergens) = l.trim_end_matches(')').split_once(" (contains ").unwrap();
+            (ingredients.split(' ').collect(), allergens.split(", ").collect())
+        })
+        .collect()
+}
+
+/// Tries every ingredient for each allergen in turn, keeping those in every
+/// food that lists the allergen and not already taken.
+fn assignments<'a>(foods: &[(Vec<&'a str>, Vec<&'a str>)], allergens: &[&'a str], chosen: &mut Vec<&'a str>, found: &mut Vec<Vec<&'a str>>) {
+    let allergen = match allergens.get(chosen.len()) {
+        Some(&allergen) => allergen,
+        None => return found.push(chosen.clone()),
+    };
+
+    let mut ingredients: Vec<&str> = foods.iter().flat_map(|(i, _)| i.iter().copied()).collect();
+    ingredients.sort_unstable();
+    ingredients.dedup();
+    for ingredient in ingredients {
+        let fits = foods.iter()
+            .filter(|(_, a)| a.contains(&allergen))
+            .all(|(i, _)| i.contains(&ingredient));
+        if fits && !chosen.contains(&ingredient) {
+            chosen.push(ingredient);
+            assignments(foods, allergens, chosen, found);
+            chosen.pop();
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..20) {
+        let input = generate::input(&mut rng(seed), size);
+        let foods = Day21::parse(&input).unwrap();
+        let output: String = foods.iter().map(|f| f.to_string() + "\n").collect();
+        prop_assert_eq!(output, input);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..12) {
+        let input = generate::input(&mut rng(seed), size);
+        let foods = parse(&input);
+        let mut allergens: Vec<&str> = foods.iter().flat_map(|(_, a)| a.iter().copied()).collect();
+        allergens.sort_unstable();
+        allergens.dedup();
+
+        let mut found = vec![];
+        assignments(&foods, &allergens, &mut vec![], &mut found);
+        prop_assert_eq!(found.len(), 1);
+        let dangerous = &found[0];
+        let safe = foods.iter().flat_map(|(i, _)| i.iter()).filter(|i| !dangerous.contains(i)).count();
+
+        let parsed = Day21::parse(&input).unwrap();
+        prop_assert_eq!(Day21::part1(&parsed).unwrap(), safe);
+        prop_assert_eq!(Day21::part2(&parsed).unwrap(), dangerous.join(","));
+    }
+}
@@ -0,0 +1,6 @@
+use day21::Day21;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day21>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,11 @@
+use aoc_common::generate::{SliceRandom, StdRng};
+
+/// The cards 1 to `2 * size` shuffled and dealt into two decks of `size`.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut cards: Vec<usize> = (1..=2 * size).collect();
+    cards.shuffle(rng);
+
+    let deck = |cards: &[usize]| cards.iter().map(|c| format!("{}\n", c)).collect::<String>();
+    format!("Player 1:\n{}\nPlayer 2:\n{}", deck(&cards[..size]), deck(&cards[size..]))
+}
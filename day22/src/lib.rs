@@ -0,0 +1,149 @@
+//! Day 22: Crab Combat. Play the crab's card game, then its recursive
+//! variant.
+
+use anyhow::{anyhow, Result};
+use aoc_common::combinator::{complete, number, tag};
+use aoc_common::diagnostic::Diagnostic;
+use aoc_common::Solver;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+pub mod generate;
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input<'a> = Decks;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Decks> {
+        parse(input)
+    }
+
+    fn part1(input: &Decks) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Decks) -> Result<u64> {
+        part2(input)
+    }
+}
+
+/// Each player's deck from top to bottom, one card per line under a
+/// `Player N:` header, separated by a blank line.
+pub fn parse(s: &str) -> Result<Decks> {
+    let (player1, player2) = s.split_once("\n\n")
+        .ok_or_else(|| Diagnostic::new(&s[s.len()..], "expected a blank line and then player 2's deck"))?;
+    Ok(Decks { player1: parse_deck(player1, "Player 1:")?, player2: parse_deck(player2, "Player 2:")? })
+}
+
+fn parse_deck(s: &str, header: &'static str) -> Result<Deck> {
+    let mut lines = s.lines();
+    complete(tag(header), lines.next().unwrap_or(s))?;
+    lines.map(|l| complete(number, l)).collect()
+}
+
+/// The winner's score under the plain rules.
+pub fn part1(decks: &Decks) -> Result<u64> {
+    let (_, deck) = play(decks, Rules::PLAIN)?;
+    Ok(score(&deck))
+}
+
+/// The winner's score under the recursive rules.
+pub fn part2(decks: &Decks) -> Result<u64> {
+    let (_, deck) = play(decks, Rules::RECURSIVE)?;
+    Ok(score(&deck))
+}
+
+/// A deck of cards, top first.
+pub type Deck = VecDeque<u32>;
+
+pub struct Decks {
+    pub player1: Deck,
+    pub player2: Deck,
+}
+
+impl fmt::Display for Decks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards = |deck: &Deck| deck.iter().map(|c| format!("{}\n", c)).collect::<String>();
+        write!(f, "Player 1:\n{}\nPlayer 2:\n{}", cards(&self.player1), cards(&self.player2))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Player {
+    One,
+    Two,
+}
+
+/// How a game is played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Whether a round where both players have enough cards is decided by
+    /// a sub-game, and a repeated round ends the game in player 1's favour.
+    pub recursive: bool,
+    /// How deeply sub-games may nest before the game is given up on.
+    pub max_depth: usize,
+}
+
+impl Rules {
+    pub const PLAIN: Rules = Rules { recursive: false, max_depth: 0 };
+    pub const RECURSIVE: Rules = Rules { recursive: true, max_depth: 1000 };
+}
+
+/// Plays a game from `decks`, returning the winner and their deck.
+///
+/// Under the plain rules, a round that repeats an earlier one means the
+/// game would go on forever, which is an error. So is a recursive game that
+/// nests deeper than its rules allow.
+pub fn play(decks: &Decks, rules: Rules) -> Result<(Player, Deck)> {
+    let (winner, player1, player2) = game(decks.player1.clone(), decks.player2.clone(), rules, 0)?;
+    match winner {
+        Player::One => Ok((winner, player1)),
+        Player::Two => Ok((winner, player2)),
+    }
+}
+
+fn game(mut player1: Deck, mut player2: Deck, rules: Rules, depth: usize) -> Result<(Player, Deck, Deck)> {
+    if depth > rules.max_depth {
+        return Err(anyhow!("sub-games nest more than {} deep", rules.max_depth));
+    }
+
+    let mut seen = HashSet::new();
+    while !player1.is_empty() && !player2.is_empty() {
+        if !seen.insert((player1.clone(), player2.clone())) {
+            if rules.recursive {
+                return Ok((Player::One, player1, player2));
+            }
+            return Err(anyhow!("the game repeats itself after {} rounds and never ends", seen.len()));
+        }
+
+        let card1 = player1.pop_front().unwrap();
+        let card2 = player2.pop_front().unwrap();
+        let winner = if rules.recursive && player1.len() >= card1 as usize && player2.len() >= card2 as usize {
+            let sub1 = player1.iter().take(card1 as usize).copied().collect();
+            let sub2 = player2.iter().take(card2 as usize).copied().collect();
+            game(sub1, sub2, rules, depth + 1)?.0
+        }
+        else if card1 > card2 {
+            Player::One
+        }
+        else {
+            Player::Two
+        };
+
+        match winner {
+            Player::One => player1.extend([card1, card2].iter()),
+            Player::Two => player2.extend([card2, card1].iter()),
+        }
+    }
+
+    let winner = if player2.is_empty() { Player::One } else { Player::Two };
+    Ok((winner, player1, player2))
+}
+
+/// Each card times its position from the bottom of the deck, summed.
+pub fn score(deck: &Deck) -> u64 {
+    deck.iter().rev().zip(1..).map(|(&card, i)| card as u64 * i).sum()
+}
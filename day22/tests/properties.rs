@@ -0,0 +1,60 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day22::{generate, Day22};
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+fn parse(input: &str) -> (Vec<u32>, Vec<u32>) {
+    let (player1, player2) = input.split_once("\n\n").unwrap();
+    let deck = |s: &str| s.lines().skip(1).map(|c| c.parse().unwrap()).collect();
+    (deck(player1), deck(player2))
+}
+
+/// Plays with plain vectors, returning whether player 1 won and the decks,
+/// or `None` if a plain game goes on forever.
+fn play(mut player1: Vec<u32>, mut player2: Vec<u32>, recursive: bool) -> Option<(bool, Vec<u32>, Vec<u32>)> {
+    let mut seen = HashSet::new();
+    while !player1.is_empty() && !player2.is_empty() {
+        if !seen.insert((player1.clone(), player2.clone())) {
+            return if recursive { Some((true, player1, player2)) } else { None };
+        }
+
+        let (card1, card2) = (player1.remove(0), player2.remove(0));
+        let player1_wins = if recursive && player1.len() >= card1 as usize && player2.len() >= card2 as usize {
+            play(player1[..card1 as usize].to_vec(), player2[..card2 as usize].to_vec(), true)?.0
+        }
+        else {
+            card1 > card2
+        };
+        if player1_wins {
+            player1.extend(&[card1, card2]);
+        }
+        else {
+            player2.extend(&[card2, card1]);
+        }
+    }
+    Some((player2.is_empty(), player1, player2))
+}
+
+fn score(input: &str, recursive: bool) -> Option<u64> {
+    let (player1, player2) = parse(input);
+    let (player1_wins, player1, player2) = play(player1, player2, recursive)?;
+    let deck = if player1_wins { player1 } else { player2 };
+    Some(deck.iter().rev().zip(1..).map(|(&c, i)| c as u64 * i).sum())
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips(seed: u64, size in 0usize..30) {
+        let input = generate::input(&mut rng(seed), size);
+        prop_assert_eq!(Day22::parse(&input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..12) {
+        let input = generate::input(&mut rng(seed), size);
+        let decks = Day22::parse(&input).unwrap();
+        prop_assert_eq!(Day22::part1(&decks).ok(), score(&input, false));
+        prop_assert_eq!(Day22::part2(&decks).ok(), score(&input, true));
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::Solver;
+use day22::{play, Day22, Player, Rules};
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day22>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn repeated_rounds() {
+    let decks = Day22::parse("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14\n").unwrap();
+    assert!(play(&decks, Rules::PLAIN).is_err());
+    assert_eq!(play(&decks, Rules::RECURSIVE).unwrap().0, Player::One);
+}
+
+#[test]
+fn depth_limit() {
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/input_test.txt")).unwrap();
+    let decks = Day22::parse(&input).unwrap();
+    assert!(play(&decks, Rules { max_depth: 1, ..Rules::RECURSIVE }).is_err());
+    assert_eq!(play(&decks, Rules { max_depth: 2, ..Rules::RECURSIVE }).unwrap().0, Player::Two);
+}
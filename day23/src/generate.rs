@@ -0,0 +1,8 @@
+use aoc_common::generate::{SliceRandom, StdRng};
+
+/// The cups 1 to between 5 and 9, depending on `size`, in a random order.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut labels: Vec<usize> = (1..=size.clamp(5, 9)).collect();
+    labels.shuffle(rng);
+    labels.iter().map(|l| l.to_string()).collect::<String>() + "\n"
+}
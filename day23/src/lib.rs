@@ -0,0 +1,131 @@
+//! Day 23: Crab Cups. Play the crab's game of moving cups around a circle.
+
+use anyhow::{anyhow, Result};
+use aoc_common::diagnostic::Diagnostic;
+use aoc_common::Solver;
+
+pub mod generate;
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input<'a> = Vec<u32>;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u32>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<u32>) -> Result<String> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<u32>) -> Result<u64> {
+        part2(input)
+    }
+}
+
+/// The cups' labels clockwise as a line of digits, like `389125467`. The
+/// labels must be 1 to the number of cups, in some order.
+pub fn parse(s: &str) -> Result<Vec<u32>> {
+    let line = s.trim_end();
+    let mut labels = vec![];
+    for (i, c) in line.char_indices() {
+        let label = c.to_digit(10)
+            .filter(|&d| d >= 1 && d as usize <= line.len() && !labels.contains(&d))
+            .ok_or_else(|| Diagnostic::new(&line[i..i + c.len_utf8()],
+                format!("invalid cup '{}', expected each digit from 1 to {} once", c, line.len())))?;
+        labels.push(label);
+    }
+    Ok(labels)
+}
+
+/// The labels after cup 1 once 100 moves are made.
+pub fn part1(labels: &[u32]) -> Result<String> {
+    let mut cups = CrabCups::new(labels, labels.len())?;
+    cups.run(100);
+    Ok(cups.after_1().skip(1).map(|c| c.to_string()).collect())
+}
+
+/// The product of the two cups after cup 1 once ten million moves are made
+/// with a million cups.
+pub fn part2(labels: &[u32]) -> Result<u64> {
+    let mut cups = CrabCups::new(labels, 1_000_000)?;
+    cups.run(10_000_000);
+    Ok(cups.after_1().skip(1).take(2).map(|c| c as u64).product())
+}
+
+/// A circle of cups, stored as the label of the cup clockwise of each cup
+/// so that moving cups around is a few array writes.
+pub struct CrabCups {
+    /// `next[label]` is the cup after `label`. `next[0]` is unused.
+    next: Vec<u32>,
+    current: u32,
+}
+
+impl CrabCups {
+    /// `labels`, followed by the next highest labels up to `total` cups.
+    /// There must be at least 5 cups so that every move has somewhere to go.
+    pub fn new(labels: &[u32], total: usize) -> Result<Self> {
+        let total = total.max(labels.len());
+        if total < 5 {
+            return Err(anyhow!("{} cups are too few to play with, need at least 5", total));
+        }
+
+        let labels = labels.iter().copied().chain(labels.len() as u32 + 1..=total as u32);
+        let mut next = vec![0; total + 1];
+        let mut first = None;
+        let mut last = None;
+        for label in labels {
+            match last {
+                Some(last) => next[last as usize] = label,
+                None => first = Some(label),
+            }
+            last = Some(label);
+        }
+        let current = first.unwrap();
+        next[last.unwrap() as usize] = current;
+
+        Ok(Self { next, current })
+    }
+
+    /// Makes one move: picks up the three cups after the current one, puts
+    /// them after the next lower label still on the table, and moves on to
+    /// the next cup.
+    pub fn step(&mut self) {
+        let max = self.next.len() as u32 - 1;
+        let first = self.next[self.current as usize];
+        let second = self.next[first as usize];
+        let third = self.next[second as usize];
+
+        let mut destination = self.current;
+        loop {
+            destination = if destination == 1 { max } else { destination - 1 };
+            if destination != first && destination != second && destination != third {
+                break;
+            }
+        }
+
+        self.next[self.current as usize] = self.next[third as usize];
+        self.next[third as usize] = self.next[destination as usize];
+        self.next[destination as usize] = first;
+        self.current = self.next[self.current as usize];
+    }
+
+    pub fn run(&mut self, moves: usize) {
+        for _ in 0..moves {
+            self.step();
+        }
+    }
+
+    /// Every cup, clockwise from cup 1.
+    pub fn after_1(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut cup = 1;
+        (0..self.next.len() - 1).map(move |_| {
+            let label = cup;
+            cup = self.next[cup as usize];
+            label
+        })
+    }
+}
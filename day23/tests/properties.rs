@@ -0,0 +1,42 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day23::{generate, CrabCups, Day23};
+use proptest::prelude::*;
+
+/// Plays with the circle in a vector whose first element is the current
+/// cup, then lists the cups from cup 1.
+fn play(labels: &[u32], total: usize, moves: usize) -> Vec<u32> {
+    let mut cups: Vec<u32> = labels.iter().copied().chain(labels.len() as u32 + 1..=total as u32).collect();
+    let max = cups.len() as u32;
+    for _ in 0..moves {
+        let picked: Vec<u32> = cups.drain(1..4).collect();
+        let mut destination = cups[0];
+        loop {
+            destination = if destination == 1 { max } else { destination - 1 };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        let at = cups.iter().position(|&c| c == destination).unwrap() + 1;
+        cups.splice(at..at, picked);
+        cups.rotate_left(1);
+    }
+    let one = cups.iter().position(|&c| c == 1).unwrap();
+    cups.rotate_left(one);
+    cups
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(seed: u64, size in 5usize..10, extra in 0usize..40, moves in 0usize..200) {
+        let input = generate::input(&mut rng(seed), size);
+        let labels = Day23::parse(&input).unwrap();
+
+        let mut cups = CrabCups::new(&labels, labels.len() + extra).unwrap();
+        cups.run(moves);
+        prop_assert_eq!(cups.after_1().collect::<Vec<_>>(), play(&labels, labels.len() + extra, moves));
+
+        let after_1: String = play(&labels, labels.len(), 100)[1..].iter().map(|c| c.to_string()).collect();
+        prop_assert_eq!(Day23::part1(&labels).unwrap(), after_1);
+    }
+}
@@ -0,0 +1,6 @@
+use day23::Day23;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day23>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,10 @@
+use aoc_common::generate::{lines, IndexedRandom, Rng, StdRng};
+use aoc_common::hex::Direction;
+
+/// `size` paths of up to 20 random directions.
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let len = rng.random_range(1..=20);
+        (0..len).map(|_| Direction::ALL.choose(rng).unwrap().name()).collect::<String>()
+    }))
+}
@@ -0,0 +1,84 @@
+//! Day 24: Lobby Layout. Flip hexagonal floor tiles, then let them flip
+//! themselves day by day.
+
+use anyhow::Result;
+use aoc_common::combinator::{complete, many1, one_of, PResult};
+use aoc_common::hex::{Direction, Hex};
+use aoc_common::life;
+use aoc_common::parse::map_lines;
+use aoc_common::Solver;
+use std::collections::HashSet;
+
+pub mod generate;
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input<'a> = Vec<Vec<Direction>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Direction>>> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Vec<Direction>>) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Vec<Direction>>) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
+/// One path per line from the reference tile to a tile to flip, as
+/// directions run together like `esenee`.
+pub fn parse(s: &str) -> Result<Vec<Vec<Direction>>> {
+    map_lines(s, |l| complete(path, l))
+}
+
+fn path(s: &str) -> PResult<'_, Vec<Direction>> {
+    use Direction::*;
+    many1(one_of(&[("e", E), ("se", SE), ("sw", SW), ("w", W), ("nw", NW), ("ne", NE)]))(s)
+}
+
+/// How many tiles are black once every path's tile is flipped.
+pub fn part1(paths: &[Vec<Direction>]) -> usize {
+    Floor::new(paths).black.len()
+}
+
+/// How many tiles are black after 100 days.
+pub fn part2(paths: &[Vec<Direction>]) -> usize {
+    let mut floor = Floor::new(paths);
+    for _ in 0..100 {
+        floor.step();
+    }
+    floor.black.len()
+}
+
+/// An infinite floor of white tiles, of which only the black ones are
+/// stored.
+pub struct Floor {
+    pub black: HashSet<Hex>,
+}
+
+impl Floor {
+    /// The floor after flipping the tile at the end of each path, starting
+    /// from the reference tile at the origin.
+    pub fn new(paths: &[Vec<Direction>]) -> Self {
+        let mut black = HashSet::new();
+        for path in paths {
+            let tile = path.iter().fold(Hex::default(), |hex, &d| hex.step(d));
+            if !black.remove(&tile) {
+                black.insert(tile);
+            }
+        }
+        Self { black }
+    }
+
+    /// Runs one day: a black tile stays black with 1 or 2 black neighbours,
+    /// and a white tile turns black with exactly 2.
+    pub fn step(&mut self) {
+        self.black = life::step(&self.black, |black, count| count == 2 || (black && count == 1));
+    }
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+use day24::Day24;
+
+fn main() -> Result<()> {
+    aoc_common::main::<Day24>(24)
+}
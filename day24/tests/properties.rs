@@ -0,0 +1,67 @@
+use aoc_common::generate::rng;
+use aoc_common::Solver;
+use day24::{generate, Day24, Floor};
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+/// Follows each path in "doubled" coordinates, where east is two columns
+/// over and the diagonals one column and one row.
+fn flip(input: &str) -> HashSet<(i64, i64)> {
+    let mut black = HashSet::new();
+    for line in input.lines() {
+        let (mut x, mut y) = (0, 0);
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            let (dx, dy) = match c {
+                'e' => (2, 0),
+                'w' => (-2, 0),
+                _ => {
+                    let dy = if c == 'n' { -1 } else { 1 };
+                    let dx = if chars.next() == Some('e') { 1 } else { -1 };
+                    (dx, dy)
+                },
+            };
+            x += dx;
+            y += dy;
+        }
+        if !black.remove(&(x, y)) {
+            black.insert((x, y));
+        }
+    }
+    black
+}
+
+/// Runs a day by checking every tile in a box around the black ones.
+fn step(black: &HashSet<(i64, i64)>) -> HashSet<(i64, i64)> {
+    let (min_x, max_x) = (black.iter().map(|p| p.0).min().unwrap_or(0) - 2, black.iter().map(|p| p.0).max().unwrap_or(0) + 2);
+    let (min_y, max_y) = (black.iter().map(|p| p.1).min().unwrap_or(0) - 1, black.iter().map(|p| p.1).max().unwrap_or(0) + 1);
+    let mut next = HashSet::new();
+    for y in min_y..=max_y {
+        for x in (min_x..=max_x).filter(|x| (x + y) % 2 == 0) {
+            let count = [(2, 0), (-2, 0), (1, 1), (-1, 1), (1, -1), (-1, -1)].iter()
+                .filter(|(dx, dy)| black.contains(&(x + dx, y + dy)))
+                .count();
+            if count == 2 || (black.contains(&(x, y)) && count == 1) {
+                next.insert((x, y));
+            }
+        }
+    }
+    next
+}
+
+proptest! {
+    #[test]
+    fn matches_reference(seed: u64, size in 0usize..30, days in 0usize..10) {
+        let input = generate::input(&mut rng(seed), size);
+        let paths = Day24::parse(&input).unwrap();
+        let mut black = flip(&input);
+        prop_assert_eq!(Day24::part1(&paths).unwrap(), black.len());
+
+        let mut floor = Floor::new(&paths);
+        for _ in 0..days {
+            floor.step();
+            black = step(&black);
+        }
+        prop_assert_eq!(floor.black.len(), black.len());
+    }
+}
@@ -0,0 +1,6 @@
+use day24::Day24;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day24>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,10 @@
+use crate::{transform, MODULUS, SUBJECT};
+use aoc_common::generate::{Rng, StdRng};
+
+/// The public keys of a card and a door with random loop sizes. `size` is
+/// ignored, since there are always two keys.
+pub fn input(rng: &mut StdRng, _size: usize) -> String {
+    let card = transform(SUBJECT, rng.random_range(1..MODULUS - 1));
+    let door = transform(SUBJECT, rng.random_range(1..MODULUS - 1));
+    format!("{}\n{}\n", card, door)
+}
@@ -0,0 +1,82 @@
+//! Day 25: Combo Breaker. Recover the card's and door's secret loop sizes
+//! from their public keys to find the encryption key of their handshake.
+
+use anyhow::{anyhow, Result};
+use aoc_common::combinator::{complete, number};
+use aoc_common::diagnostic::Diagnostic;
+use aoc_common::modular;
+use aoc_common::Solver;
+
+pub mod generate;
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input<'a> = PublicKeys;
+    type Answer1 = u64;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<PublicKeys> {
+        parse(input)
+    }
+
+    fn part1(input: &PublicKeys) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &PublicKeys) -> Result<&'static str> {
+        Ok(part2(input))
+    }
+}
+
+/// The subject number both public keys are transformed from.
+pub const SUBJECT: u64 = 7;
+
+/// What every transformed value is taken modulo.
+pub const MODULUS: u64 = 20201227;
+
+/// The card's public key, then the door's, one per line.
+pub fn parse(s: &str) -> Result<PublicKeys> {
+    let mut lines = s.lines();
+    let mut key = |name| match lines.next() {
+        Some(line) => complete(number, line),
+        None => Err(Diagnostic::new(&s[s.len()..], format!("expected the {}'s public key", name)).into()),
+    };
+    let keys = PublicKeys { card: key("card")?, door: key("door")? };
+    if let Some(extra) = lines.next() {
+        return Err(Diagnostic::new(extra, "expected only two public keys").into());
+    }
+    Ok(keys)
+}
+
+/// The encryption key the card and door agree on.
+pub fn part1(keys: &PublicKeys) -> Result<u64> {
+    let card_loop_size = loop_size(keys.card)?;
+    Ok(transform(keys.door, card_loop_size))
+}
+
+/// Day 25 only has one puzzle.
+pub fn part2(_keys: &PublicKeys) -> &'static str {
+    "no puzzle"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKeys {
+    pub card: u64,
+    pub door: u64,
+}
+
+/// `subject` transformed `loop_size` times: multiplied by itself, modulo
+/// [`MODULUS`].
+pub fn transform(subject: u64, loop_size: u64) -> u64 {
+    modular::pow(subject, loop_size, MODULUS)
+}
+
+/// The smallest loop size that transforms [`SUBJECT`] into `public_key`.
+pub fn loop_size(public_key: u64) -> Result<u64> {
+    if public_key >= MODULUS {
+        return Err(anyhow!("public key {} is not less than {}", public_key, MODULUS));
+    }
+    modular::discrete_log(SUBJECT, public_key, MODULUS)
+        .ok_or_else(|| anyhow!("no loop size transforms {} into {}", SUBJECT, public_key))
+}
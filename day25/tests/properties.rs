@@ -0,0 +1,37 @@
+use aoc_common::generate::rng;
+use aoc_common::modular;
+use aoc_common::Solver;
+use day25::{generate, loop_size, transform, Day25, SUBJECT};
+use proptest::prelude::*;
+
+/// The smallest `x` with `base^x % modulus == target`, by trying each `x`.
+fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    let mut power = 1 % modulus;
+    for x in 0..modulus {
+        if power == target {
+            return Some(x);
+        }
+        power = power * base % modulus;
+    }
+    None
+}
+
+proptest! {
+    #[test]
+    fn handshake_agrees(seed: u64) {
+        let input = generate::input(&mut rng(seed), 2);
+        let keys = Day25::parse(&input).unwrap();
+
+        let card_loop_size = loop_size(keys.card).unwrap();
+        let door_loop_size = loop_size(keys.door).unwrap();
+        prop_assert_eq!(transform(SUBJECT, card_loop_size), keys.card);
+        prop_assert_eq!(transform(SUBJECT, door_loop_size), keys.door);
+        prop_assert_eq!(Day25::part1(&keys).unwrap(), transform(keys.card, door_loop_size));
+    }
+
+    #[test]
+    fn discrete_log_matches_brute_force(base in 1u64..200, target in 0u64..200, modulus in 2u64..200) {
+        let expected = if modular::inverse(base, modulus).is_some() { discrete_log(base, target, modulus) } else { None };
+        prop_assert_eq!(modular::discrete_log(base, target, modulus), expected);
+    }
+}
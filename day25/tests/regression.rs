@@ -0,0 +1,6 @@
+use day25::Day25;
+
+#[test]
+fn answers() {
+    aoc_common::regression::check::<Day25>(env!("CARGO_MANIFEST_DIR"));
+}